@@ -18,6 +18,7 @@ pin_project! {
         rx_frame: mpsc::Receiver<Frame<D>>,
         #[pin]
         rx_error: oneshot::Receiver<E>,
+        end: Option<ChannelEnd>,
     }
 }
 
@@ -30,10 +31,32 @@ impl<D, E> Channel<D, E> {
     pub fn new(buffer: usize) -> (Sender<D, E>, Self) {
         let (tx_frame, rx_frame) = mpsc::channel(buffer);
         let (tx_error, rx_error) = oneshot::channel();
-        (Sender { tx_frame, tx_error }, Self { rx_frame, rx_error })
+        let body = Self {
+            rx_frame,
+            rx_error,
+            end: None,
+        };
+        (Sender { tx_frame, tx_error }, body)
+    }
+
+    /// Returns how the body ended, or `None` if it has not ended yet.
+    ///
+    /// This is set once the body has yielded its last frame: [`ChannelEnd::Closed`] if the
+    /// [`Sender`] was dropped, or [`ChannelEnd::Aborted`] if [`Sender::abort`] was called.
+    pub fn end(&self) -> Option<ChannelEnd> {
+        self.end
     }
 }
 
+/// How a [`Channel`] body ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChannelEnd {
+    /// The [`Sender`] was dropped, ending the body normally.
+    Closed,
+    /// The body was aborted with [`Sender::abort`], and yielded an error.
+    Aborted,
+}
+
 impl<D, E> Body for Channel<D, E>
 where
     D: Buf,
@@ -47,6 +70,10 @@ where
     ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
         let this = self.project();
 
+        if this.end.is_some() {
+            return Poll::Ready(None);
+        }
+
         match this.rx_frame.poll_recv(cx) {
             Poll::Ready(frame @ Some(_)) => return Poll::Ready(frame.map(Ok)),
             Poll::Ready(None) | Poll::Pending => {}
@@ -54,8 +81,14 @@ where
 
         use core::future::Future;
         match this.rx_error.poll(cx) {
-            Poll::Ready(Ok(error)) => return Poll::Ready(Some(Err(error))),
-            Poll::Ready(Err(_)) => return Poll::Ready(None),
+            Poll::Ready(Ok(error)) => {
+                *this.end = Some(ChannelEnd::Aborted);
+                return Poll::Ready(Some(Err(error)));
+            }
+            Poll::Ready(Err(_)) => {
+                *this.end = Some(ChannelEnd::Closed);
+                return Poll::Ready(None);
+            }
             Poll::Pending => {}
         }
 
@@ -68,6 +101,7 @@ impl<D, E: std::fmt::Debug> std::fmt::Debug for Channel<D, E> {
         f.debug_struct("Channel")
             .field("rx_frame", &self.rx_frame)
             .field("rx_error", &self.rx_error)
+            .field("end", &self.end)
             .finish()
     }
 }
@@ -94,7 +128,54 @@ impl<D, E> Sender<D, E> {
         self.send(Frame::trailers(trailers)).await
     }
 
+    /// Attempt to send a frame on the channel without waiting.
+    ///
+    /// If the channel is full or the receiver has been dropped, the frame is returned in the
+    /// error.
+    pub fn try_send(&mut self, frame: Frame<D>) -> Result<(), TrySendError<Frame<D>>> {
+        self.tx_frame.try_send(frame).map_err(|err| match err {
+            mpsc::error::TrySendError::Full(frame) => TrySendError::Full(frame),
+            mpsc::error::TrySendError::Closed(frame) => TrySendError::Closed(frame),
+        })
+    }
+
+    /// Attempt to send data on the channel without waiting.
+    ///
+    /// If the channel is full or the receiver has been dropped, the data is returned in the
+    /// error.
+    pub fn try_send_data(&mut self, buf: D) -> Result<(), TrySendError<D>> {
+        self.try_send(Frame::data(buf)).map_err(|err| {
+            err.map(|frame| match frame.into_data() {
+                Ok(buf) => buf,
+                Err(_) => unreachable!("frame is a data frame"),
+            })
+        })
+    }
+
+    /// Returns the number of frames that can currently be sent without waiting.
+    pub fn capacity(&self) -> usize {
+        self.tx_frame.capacity()
+    }
+
+    /// Returns `true` if the [`Channel`] body has been dropped.
+    ///
+    /// Once it has, every attempt to send fails.
+    pub fn is_closed(&self) -> bool {
+        self.tx_frame.is_closed()
+    }
+
+    /// Wait for the [`Channel`] body to be dropped.
+    ///
+    /// This allows a producer to stop early when nobody is reading the body anymore.
+    pub async fn closed(&self) {
+        self.tx_frame.closed().await
+    }
+
     /// Aborts the body in an abnormal fashion.
+    ///
+    /// The [`Channel`] yields `error` after any frames that were already sent, while dropping
+    /// the sender ends the body normally. [`Channel::end`] tells the two apart once the body has
+    /// ended.
     pub fn abort(self, error: E) {
         self.tx_error.send(error).ok();
     }
@@ -122,6 +203,44 @@ impl Display for SendError {
 
 impl std::error::Error for SendError {}
 
+/// The error returned if [`Sender`] fails to send without waiting.
+///
+/// The value that could not be sent is returned in the error.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TrySendError<T> {
+    /// The channel is full.
+    Full(T),
+    /// The receiver has been dropped.
+    Closed(T),
+}
+
+impl<T> TrySendError<T> {
+    /// Consume the error, returning the value that could not be sent.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Full(value) | Self::Closed(value) => value,
+        }
+    }
+
+    fn map<U>(self, f: impl FnOnce(T) -> U) -> TrySendError<U> {
+        match self {
+            Self::Full(value) => TrySendError::Full(f(value)),
+            Self::Closed(value) => TrySendError::Closed(f(value)),
+        }
+    }
+}
+
+impl<T> Display for TrySendError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Full(_) => write!(f, "failed to send frame: channel is full"),
+            Self::Closed(_) => write!(f, "failed to send frame: channel is closed"),
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::error::Error for TrySendError<T> {}
+
 #[cfg(test)]
 mod tests {
     use bytes::Bytes;
@@ -141,6 +260,36 @@ mod tests {
         assert!(collected.to_bytes().is_empty());
     }
 
+    #[tokio::test]
+    async fn reports_how_the_body_ended() {
+        let (tx, mut body) = Channel::<Bytes, Error>::new(1);
+        assert_eq!(body.end(), None);
+        drop(tx);
+        assert!(body.frame().await.is_none());
+        assert_eq!(body.end(), Some(ChannelEnd::Closed));
+
+        let (tx, mut body) = Channel::<Bytes, Error>::new(1);
+        tx.abort(MSG);
+        assert_eq!(body.frame().await.unwrap().unwrap_err(), MSG);
+        assert_eq!(body.end(), Some(ChannelEnd::Aborted));
+        assert!(body.frame().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn capacity_and_closed() {
+        let (mut tx, body) = Channel::<Bytes>::new(2);
+        assert_eq!(tx.capacity(), 2);
+
+        tx.send_data(Bytes::from("a")).await.unwrap();
+        assert_eq!(tx.capacity(), 1);
+        assert!(!tx.is_closed());
+
+        tokio::spawn(async move { drop(body) });
+        tx.closed().await;
+        assert!(tx.is_closed());
+        assert!(tx.send_data(Bytes::from("b")).await.is_err());
+    }
+
     #[tokio::test]
     async fn can_send_data() {
         let (mut tx, body) = Channel::<Bytes>::new(1024);
@@ -193,6 +342,20 @@ mod tests {
         assert_eq!(collected.to_bytes(), "Hello!");
     }
 
+    #[tokio::test]
+    async fn try_send_data_returns_data() {
+        let (mut tx, body) = Channel::<Bytes>::new(1);
+
+        tx.try_send_data(Bytes::from("Hel")).unwrap();
+        let err = tx.try_send_data(Bytes::from("lo!")).unwrap_err();
+        assert_eq!(err, TrySendError::Full(Bytes::from("lo!")));
+
+        drop(body);
+        let err = tx.try_send_data(Bytes::from("lo!")).unwrap_err();
+        assert_eq!(err, TrySendError::Closed(Bytes::from("lo!")));
+        assert_eq!(err.into_inner(), "lo!");
+    }
+
     /// A stand-in for an error type, for unit tests.
     type Error = &'static str;
     /// An example error message.
@@ -1,12 +1,13 @@
 use std::{
     convert::Infallible,
     pin::Pin,
+    string::FromUtf8Error,
     task::{Context, Poll},
 };
 
 use bytes::{Buf, Bytes};
 use http::HeaderMap;
-use http_body::{Body, Frame};
+use http_body::{Body, Frame, SizeHint};
 
 use crate::util::BufList;
 
@@ -14,7 +15,7 @@ use crate::util::BufList;
 /// and trailers.
 ///
 /// [`BodyExt::collect`]: crate::BodyExt::collect
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Collected<B> {
     bufs: BufList<B>,
     trailers: Option<HeaderMap>,
@@ -28,7 +29,27 @@ impl<B: Buf> Collected<B> {
         self.trailers.as_ref()
     }
 
+    /// If there is a trailers frame buffered, returns a mutable reference to it.
+    ///
+    /// Returns `None` if the body contained no trailers.
+    pub fn trailers_mut(&mut self) -> Option<&mut HeaderMap> {
+        self.trailers.as_mut()
+    }
+
+    /// Transform the buffered trailers, if there are any.
+    pub fn map_trailers<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(HeaderMap) -> HeaderMap,
+    {
+        self.trailers = self.trailers.map(f);
+        self
+    }
+
     /// Aggregate this buffered into a [`Buf`].
+    ///
+    /// The collected DATA frames are kept as a list of buffers and are not copied into one
+    /// allocation, and [`Buf::chunks_vectored`] yields each of them, so the result can be passed
+    /// straight to a vectored writer. Use [`Collected::to_bytes`] if contiguous data is needed.
     pub fn aggregate(self) -> impl Buf {
         self.bufs
     }
@@ -38,6 +59,45 @@ impl<B: Buf> Collected<B> {
         self.bufs.copy_to_bytes(self.bufs.remaining())
     }
 
+    /// Convert this body into a [`Vec<u8>`].
+    ///
+    /// Any trailers are dropped.
+    pub fn to_vec(self) -> Vec<u8> {
+        self.to_bytes().into()
+    }
+
+    /// Convert this body into a [`String`], returning an error if it is not valid UTF-8.
+    ///
+    /// Any trailers are dropped.
+    pub fn to_string(self) -> Result<String, FromUtf8Error> {
+        String::from_utf8(self.to_vec())
+    }
+
+    /// Convert this body into a [`std::io::Read`] over the collected data.
+    ///
+    /// The data is read directly from the collected buffers, without copying them into a
+    /// single allocation first. Any trailers are dropped.
+    pub fn reader(self) -> impl std::io::Read {
+        self.bufs.reader()
+    }
+
+    /// Convert this into a body that yields the collected data as a single DATA frame,
+    /// followed by the collected trailers.
+    ///
+    /// `Collected` itself implements [`Body`], yielding each collected frame in turn. This
+    /// instead joins the data into one [`Bytes`], which avoids many small frames when
+    /// forwarding a buffered body.
+    pub fn into_body(mut self) -> Collected<Bytes> {
+        let mut bufs = BufList::default();
+        if self.bufs.has_remaining() {
+            bufs.push(self.bufs.copy_to_bytes(self.bufs.remaining()));
+        }
+        Collected {
+            bufs,
+            trailers: self.trailers,
+        }
+    }
+
     pub(crate) fn push_frame(&mut self, frame: Frame<B>) {
         let frame = match frame.into_data() {
             Ok(data) => {
@@ -79,6 +139,14 @@ impl<B: Buf> Body for Collected<B> {
 
         Poll::Ready(Some(Ok(frame)))
     }
+
+    fn is_end_stream(&self) -> bool {
+        !self.bufs.has_remaining() && self.trailers.is_none()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::with_exact(self.bufs.remaining() as u64)
+    }
 }
 
 impl<B> Default for Collected<B> {
@@ -113,6 +181,15 @@ mod tests {
         assert_eq!(&buf.copy_to_bytes(buf.remaining())[..], &b"hello"[..]);
     }
 
+    #[tokio::test]
+    async fn single_frame_to_bytes_does_not_copy() {
+        let data = Bytes::from(vec![1, 2, 3]);
+        let body = Full::<_, Infallible>::new(data.clone());
+
+        let bytes = body.collect().await.unwrap().to_bytes();
+        assert_eq!(bytes.as_ptr(), data.as_ptr());
+    }
+
     #[tokio::test]
     async fn segmented_body() {
         let bufs = [&b"hello"[..], &b"world"[..], &b"!"[..]];
@@ -125,6 +202,19 @@ mod tests {
         assert_eq!(&buf.copy_to_bytes(buf.remaining())[..], b"helloworld!");
     }
 
+    #[tokio::test]
+    async fn aggregate_is_vectored() {
+        let bufs = [&b"hello"[..], &b"world"[..], &b"!"[..]];
+        let body = StreamBody::new(stream::iter(bufs.map(Frame::data).map(Ok::<_, Infallible>)));
+
+        let buf = body.collect().await.unwrap().aggregate();
+        let mut slices = [std::io::IoSlice::new(&[]); 4];
+        assert_eq!(buf.chunks_vectored(&mut slices), 3);
+        assert_eq!(&*slices[0], b"hello");
+        assert_eq!(&*slices[1], b"world");
+        assert_eq!(&*slices[2], b"!");
+    }
+
     #[tokio::test]
     async fn delayed_segments() {
         let one = stream::once(async { Ok::<_, Infallible>(Frame::data(&b"hello "[..])) });
@@ -165,6 +255,22 @@ mod tests {
         assert_eq!(&buf.copy_to_bytes(buf.remaining())[..], b"helloworld!");
     }
 
+    #[tokio::test]
+    async fn to_vec_and_to_string() {
+        let bufs = [&b"hello"[..], &b" "[..], &b"world"[..]];
+        let body = StreamBody::new(stream::iter(bufs.map(Frame::data).map(Ok::<_, Infallible>)));
+        let buffered = body.collect().await.unwrap();
+        assert_eq!(buffered.to_vec(), b"hello world");
+
+        let body = Full::<_, Infallible>::new(&b"hello"[..]);
+        let buffered = body.collect().await.unwrap();
+        assert_eq!(buffered.to_string().unwrap(), "hello");
+
+        let body = Full::<_, Infallible>::new(&b"\xff"[..]);
+        let buffered = body.collect().await.unwrap();
+        assert!(buffered.to_string().is_err());
+    }
+
     /// Test for issue [#88](https://github.com/hyperium/http-body/issues/88).
     #[tokio::test]
     async fn empty_frame() {
@@ -175,4 +281,68 @@ mod tests {
 
         assert_eq!(buffered.to_bytes().len(), 0);
     }
+
+    #[tokio::test]
+    async fn reader() {
+        use std::io::Read;
+
+        let body = Full::<_, Infallible>::new(&b"hello"[..])
+            .chain(Full::<_, Infallible>::new(&b" world"[..]));
+        let mut reader = body.collect().await.unwrap().reader();
+
+        let mut buf = [0; 7];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"hello w");
+
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "orld");
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn mutate_trailers() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("connection", "close".parse().unwrap());
+        trailers.insert("x-checksum", "abc".parse().unwrap());
+        let body = Full::<_, Infallible>::with_trailers(&b"hello"[..], trailers);
+
+        let mut collected = body.collect().await.unwrap();
+        collected.trailers_mut().unwrap().remove("connection");
+        let collected = collected.map_trailers(|mut trailers| {
+            trailers.insert("x-extra", "1".parse().unwrap());
+            trailers
+        });
+
+        let trailers = collected.trailers().unwrap();
+        assert!(!trailers.contains_key("connection"));
+        assert_eq!(trailers["x-checksum"], "abc");
+        assert_eq!(trailers["x-extra"], "1");
+    }
+
+    #[tokio::test]
+    async fn into_body_round_trips() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("x-checksum", "abc".parse().unwrap());
+        let body = Full::<_, Infallible>::new(&b"hello"[..]).chain(
+            Full::<_, Infallible>::with_trailers(&b" world"[..], trailers),
+        );
+
+        let mut body = body.collect().await.unwrap().into_body();
+        assert_eq!(body.size_hint().exact(), Some(11));
+        assert!(!body.is_end_stream());
+
+        let data = body.frame().await.unwrap().unwrap().into_data().unwrap();
+        assert_eq!(data, "hello world");
+        let trailers = body
+            .frame()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_trailers()
+            .unwrap();
+        assert_eq!(trailers["x-checksum"], "abc");
+        assert!(body.is_end_stream());
+        assert!(body.frame().await.is_none());
+    }
 }
@@ -0,0 +1,153 @@
+use bytes::Buf;
+use futures_core::ready;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`assert_size_hint`] combinator.
+    ///
+    /// [`assert_size_hint`]: crate::BodyExt::assert_size_hint
+    #[derive(Clone, Debug)]
+    pub struct AssertSizeHint<B> {
+        #[pin]
+        inner: B,
+        hint: SizeHint,
+        emitted: u64,
+    }
+}
+
+impl<B: Body> AssertSizeHint<B> {
+    #[inline]
+    pub(crate) fn new(body: B) -> Self {
+        Self {
+            hint: body.size_hint(),
+            inner: body,
+            emitted: 0,
+        }
+    }
+}
+
+impl<B> AssertSizeHint<B> {
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for AssertSizeHint<B>
+where
+    B: Body,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        let frame = ready!(this.inner.poll_frame(cx));
+
+        match &frame {
+            Some(Ok(frame)) => {
+                if let Some(data) = frame.data_ref() {
+                    *this.emitted += data.remaining() as u64;
+                    if let Some(upper) = this.hint.upper() {
+                        assert!(
+                            *this.emitted <= upper,
+                            "body yielded {} bytes, more than its size hint upper bound of {}",
+                            this.emitted,
+                            upper
+                        );
+                    }
+                }
+            }
+            Some(Err(_)) => {}
+            None => assert!(
+                *this.emitted >= this.hint.lower(),
+                "body ended after {} bytes, less than its size hint lower bound of {}",
+                this.emitted,
+                this.hint.lower()
+            ),
+        }
+
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::{BodyExt, Full};
+
+    struct Lying(Full<Bytes, Infallible>, SizeHint);
+
+    impl Body for Lying {
+        type Data = Bytes;
+        type Error = Infallible;
+
+        fn poll_frame(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+            Pin::new(&mut self.0).poll_frame(cx)
+        }
+
+        fn size_hint(&self) -> SizeHint {
+            self.1.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn accepts_accurate_hint() {
+        let body = Full::<_, Infallible>::new(Bytes::from("hello")).assert_size_hint();
+        assert_eq!(body.collect().await.unwrap().to_bytes(), "hello");
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "more than its size hint upper bound of 3")]
+    async fn panics_above_upper_bound() {
+        let body = Lying(Full::new(Bytes::from("hello")), SizeHint::with_exact(3));
+        let _ = body.assert_size_hint().collect().await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "less than its size hint lower bound of 8")]
+    async fn panics_below_lower_bound() {
+        let mut hint = SizeHint::new();
+        hint.set_lower(8);
+        let body = Lying(Full::new(Bytes::from("hello")), hint);
+        let _ = body.assert_size_hint().collect().await;
+    }
+}
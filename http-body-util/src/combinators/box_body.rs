@@ -1,6 +1,7 @@
 use bytes::Buf;
 use http_body::{Body, Frame, SizeHint};
 use std::{
+    any::TypeId,
     fmt,
     pin::Pin,
     task::{Context, Poll},
@@ -8,12 +9,27 @@ use std::{
 
 /// A boxed [`Body`] trait object.
 pub struct BoxBody<D, E> {
-    inner: Pin<Box<dyn Body<Data = D, Error = E> + Send + Sync + 'static>>,
+    inner: Pin<Box<dyn AnyBody<Data = D, Error = E> + Send + Sync + 'static>>,
 }
 
 /// A boxed [`Body`] trait object that is !Sync.
 pub struct UnsyncBoxBody<D, E> {
-    inner: Pin<Box<dyn Body<Data = D, Error = E> + Send + 'static>>,
+    inner: Pin<Box<dyn AnyBody<Data = D, Error = E> + Send + 'static>>,
+}
+
+// Keeping the type id in the vtable, rather than next to the box, keeps boxed bodies the size
+// of a single fat pointer.
+trait AnyBody: Body {
+    fn type_id(&self) -> TypeId;
+}
+
+impl<B> AnyBody for B
+where
+    B: Body + 'static,
+{
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<B>()
+    }
 }
 
 impl<D, E> BoxBody<D, E> {
@@ -27,6 +43,67 @@ impl<D, E> BoxBody<D, E> {
             inner: Box::pin(body),
         }
     }
+
+    /// Returns `true` if the boxed body is of type `T`.
+    pub fn is<T: 'static>(&self) -> bool
+    where
+        D: Buf,
+    {
+        AnyBody::type_id(&*self.inner) == TypeId::of::<T>()
+    }
+
+    /// Returns a reference to the boxed body if it is of type `T`.
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T>
+    where
+        D: Buf,
+    {
+        if self.is::<T>() {
+            let ptr = self.inner.as_ref().get_ref() as *const _ as *const T;
+            // SAFETY:
+            // The boxed body was created from a `T`, as checked above.
+            Some(unsafe { &*ptr })
+        } else {
+            None
+        }
+    }
+
+    /// Attempt to downcast the boxed body to a concrete type, returning it by value.
+    ///
+    /// The body is pinned while boxed, so this requires `T: Unpin`. If the body is not of type
+    /// `T`, `self` is returned unchanged.
+    pub fn downcast<T: Unpin + 'static>(self) -> Result<T, Self>
+    where
+        D: Buf,
+    {
+        if self.is::<T>() {
+            // SAFETY:
+            // `T` is `Unpin`, so the body may be moved out of its pinned box.
+            let inner = unsafe { Pin::into_inner_unchecked(self.inner) };
+            let ptr = Box::into_raw(inner) as *mut T;
+            // SAFETY:
+            // The boxed body was created from a `T`, as checked above, and the pointer came
+            // from `Box::into_raw`.
+            Ok(*unsafe { Box::from_raw(ptr) })
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<D, E> BoxBody<D, E> {
+    /// Convert this body into an [`UnsyncBoxBody`], without reboxing it.
+    ///
+    /// There is no conversion in the other direction: once a body has been erased into an
+    /// `UnsyncBoxBody`, there is no way to prove that it is `Sync`.
+    pub fn into_unsync(self) -> UnsyncBoxBody<D, E> {
+        UnsyncBoxBody { inner: self.inner }
+    }
+}
+
+impl<D, E> From<BoxBody<D, E>> for UnsyncBoxBody<D, E> {
+    fn from(body: BoxBody<D, E>) -> Self {
+        body.into_unsync()
+    }
 }
 
 impl<D, E> fmt::Debug for BoxBody<D, E> {
@@ -80,6 +157,51 @@ impl<D, E> UnsyncBoxBody<D, E> {
             inner: Box::pin(body),
         }
     }
+
+    /// Returns `true` if the boxed body is of type `T`.
+    pub fn is<T: 'static>(&self) -> bool
+    where
+        D: Buf,
+    {
+        AnyBody::type_id(&*self.inner) == TypeId::of::<T>()
+    }
+
+    /// Returns a reference to the boxed body if it is of type `T`.
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T>
+    where
+        D: Buf,
+    {
+        if self.is::<T>() {
+            let ptr = self.inner.as_ref().get_ref() as *const _ as *const T;
+            // SAFETY:
+            // The boxed body was created from a `T`, as checked above.
+            Some(unsafe { &*ptr })
+        } else {
+            None
+        }
+    }
+
+    /// Attempt to downcast the boxed body to a concrete type, returning it by value.
+    ///
+    /// The body is pinned while boxed, so this requires `T: Unpin`. If the body is not of type
+    /// `T`, `self` is returned unchanged.
+    pub fn downcast<T: Unpin + 'static>(self) -> Result<T, Self>
+    where
+        D: Buf,
+    {
+        if self.is::<T>() {
+            // SAFETY:
+            // `T` is `Unpin`, so the body may be moved out of its pinned box.
+            let inner = unsafe { Pin::into_inner_unchecked(self.inner) };
+            let ptr = Box::into_raw(inner) as *mut T;
+            // SAFETY:
+            // The boxed body was created from a `T`, as checked above, and the pointer came
+            // from `Box::into_raw`.
+            Ok(*unsafe { Box::from_raw(ptr) })
+        } else {
+            Err(self)
+        }
+    }
 }
 
 impl<D, E> fmt::Debug for UnsyncBoxBody<D, E> {
@@ -120,3 +242,238 @@ where
         UnsyncBoxBody::new(crate::Empty::new())
     }
 }
+
+// === LocalBoxBody ===
+
+/// A boxed [`Body`] trait object that is neither `Send` nor `Sync`.
+///
+/// This allows erasing bodies that cannot be sent to another thread, such as those holding an
+/// `Rc`, for use on single-threaded runtimes. As a consequence, a `LocalBoxBody` cannot be sent
+/// to another thread either.
+pub struct LocalBoxBody<D, E> {
+    inner: Pin<Box<dyn Body<Data = D, Error = E> + 'static>>,
+}
+
+impl<D, E> LocalBoxBody<D, E> {
+    /// Create a new `LocalBoxBody`.
+    pub fn new<B>(body: B) -> Self
+    where
+        B: Body<Data = D, Error = E> + 'static,
+        D: Buf,
+    {
+        Self {
+            inner: Box::pin(body),
+        }
+    }
+}
+
+impl<D, E> fmt::Debug for LocalBoxBody<D, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalBoxBody").finish()
+    }
+}
+
+impl<D, E> Body for LocalBoxBody<D, E>
+where
+    D: Buf,
+{
+    type Data = D;
+    type Error = E;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        self.inner.as_mut().poll_frame(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl<D, E> Default for LocalBoxBody<D, E>
+where
+    D: Buf + 'static,
+    E: 'static,
+{
+    fn default() -> Self {
+        LocalBoxBody::new(crate::Empty::new())
+    }
+}
+
+// === CloneBoxBody ===
+
+/// A boxed [`Body`] trait object that can be cloned.
+///
+/// The erased body must implement [`Clone`], which is what allows the box itself to be cloned,
+/// for example to re-send a request body when retrying. Like [`BoxBody`], it is `Send + Sync`.
+///
+/// Cloning duplicates the current state of the body, so a clone of a body that has already
+/// yielded some frames will not yield them again. Clone it before polling to replay it in full.
+pub struct CloneBoxBody<D, E> {
+    inner: Pin<Box<dyn CloneBody<Data = D, Error = E> + Send + Sync + 'static>>,
+}
+
+trait CloneBody: Body {
+    fn clone_box(
+        &self,
+    ) -> Pin<Box<dyn CloneBody<Data = Self::Data, Error = Self::Error> + Send + Sync>>;
+}
+
+impl<B> CloneBody for B
+where
+    B: Body + Clone + Send + Sync + 'static,
+{
+    fn clone_box(&self) -> Pin<Box<dyn CloneBody<Data = B::Data, Error = B::Error> + Send + Sync>> {
+        Box::pin(self.clone())
+    }
+}
+
+impl<D, E> CloneBoxBody<D, E> {
+    /// Create a new `CloneBoxBody`.
+    pub fn new<B>(body: B) -> Self
+    where
+        B: Body<Data = D, Error = E> + Clone + Send + Sync + 'static,
+        D: Buf,
+    {
+        Self {
+            inner: Box::pin(body),
+        }
+    }
+}
+
+impl<D, E> Clone for CloneBoxBody<D, E>
+where
+    D: Buf,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.as_ref().get_ref().clone_box(),
+        }
+    }
+}
+
+impl<D, E> fmt::Debug for CloneBoxBody<D, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CloneBoxBody").finish()
+    }
+}
+
+impl<D, E> Body for CloneBoxBody<D, E>
+where
+    D: Buf,
+{
+    type Data = D;
+    type Error = E;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        self.inner.as_mut().poll_frame(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl<D, E> Default for CloneBoxBody<D, E>
+where
+    D: Buf + 'static,
+    E: 'static,
+{
+    fn default() -> Self {
+        CloneBoxBody::new(crate::Empty::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::{BodyExt, Empty, Full};
+
+    #[tokio::test]
+    async fn downcast_boxed_body() {
+        let body = Full::<_, Infallible>::new(Bytes::from("hello")).boxed();
+
+        assert!(body.is::<Full<Bytes, Infallible>>());
+        assert!(body.downcast_ref::<Empty<Bytes, Infallible>>().is_none());
+        assert_eq!(
+            body.downcast_ref::<Full<Bytes, Infallible>>()
+                .unwrap()
+                .size_hint()
+                .exact(),
+            Some(5)
+        );
+
+        let body = body.downcast::<Empty<Bytes, Infallible>>().unwrap_err();
+        let full = body.downcast::<Full<Bytes, Infallible>>().unwrap();
+        let collected = full.collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), "hello");
+    }
+
+    #[test]
+    fn downcast_unsync_boxed_body() {
+        let body = Empty::<Bytes, Infallible>::new().boxed_unsync();
+
+        assert!(body.downcast_ref::<Empty<Bytes, Infallible>>().is_some());
+        assert!(body.downcast::<Empty<Bytes, Infallible>>().is_ok());
+    }
+
+    #[tokio::test]
+    async fn local_box_body() {
+        let data = std::rc::Rc::new(Bytes::from("hello"));
+        let body = Full::<_, Infallible>::new(Bytes::clone(&data))
+            .map_frame(move |frame| {
+                let _ = &data;
+                frame
+            })
+            .boxed_local();
+
+        assert_eq!(body.collect().await.unwrap().to_bytes(), "hello");
+    }
+
+    #[tokio::test]
+    async fn clone_box_body() {
+        let body = Full::<_, Infallible>::new(Bytes::from("hello")).boxed_clone();
+        let retry = body.clone();
+
+        assert_eq!(body.collect().await.unwrap().to_bytes(), "hello");
+        assert_eq!(retry.collect().await.unwrap().to_bytes(), "hello");
+    }
+
+    #[test]
+    fn box_body_is_a_single_pointer() {
+        let size = std::mem::size_of::<Box<dyn Body<Data = Bytes, Error = Infallible>>>();
+        assert_eq!(std::mem::size_of::<BoxBody<Bytes, Infallible>>(), size);
+        assert_eq!(
+            std::mem::size_of::<UnsyncBoxBody<Bytes, Infallible>>(),
+            size
+        );
+    }
+
+    #[tokio::test]
+    async fn box_body_into_unsync() {
+        let body = Full::<_, Infallible>::new(Bytes::from("hello"))
+            .boxed()
+            .into_unsync();
+
+        assert!(body.is::<Full<Bytes, Infallible>>());
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), "hello");
+    }
+}
@@ -0,0 +1,184 @@
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`buffer`] combinator.
+    ///
+    /// [`buffer`]: crate::BodyExt::buffer
+    #[derive(Debug)]
+    pub struct Buffered<B> {
+        #[pin]
+        inner: B,
+        buf: BytesMut,
+        min_chunk: usize,
+        held: Option<Frame<Bytes>>,
+        finished: bool,
+    }
+}
+
+impl<B> Buffered<B> {
+    #[inline]
+    pub(crate) fn new(body: B, min_chunk: usize) -> Self {
+        Self {
+            inner: body,
+            buf: BytesMut::new(),
+            min_chunk,
+            held: None,
+            finished: false,
+        }
+    }
+
+    /// Returns the number of bytes buffered but not yet yielded.
+    pub fn buffered(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+}
+
+impl<B> Body for Buffered<B>
+where
+    B: Body,
+{
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        if let Some(frame) = this.held.take() {
+            return Poll::Ready(Some(Ok(frame)));
+        }
+
+        while !*this.finished {
+            let frame = match this.inner.as_mut().poll_frame(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Some(Ok(frame))) => frame,
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(None) => {
+                    *this.finished = true;
+                    break;
+                }
+            };
+
+            match frame.into_data() {
+                Ok(mut data) => {
+                    if this.buf.is_empty() && data.remaining() >= *this.min_chunk {
+                        let data = data.copy_to_bytes(data.remaining());
+                        return Poll::Ready(Some(Ok(Frame::data(data))));
+                    }
+                    this.buf.put(data);
+                    if this.buf.len() >= *this.min_chunk {
+                        let data = this.buf.split().freeze();
+                        return Poll::Ready(Some(Ok(Frame::data(data))));
+                    }
+                }
+                Err(frame) => {
+                    // Any frame other than DATA flushes the buffer, and is yielded after it.
+                    let frame = frame.map_data(|mut data| data.copy_to_bytes(data.remaining()));
+                    if this.buf.is_empty() {
+                        return Poll::Ready(Some(Ok(frame)));
+                    }
+                    *this.held = Some(frame);
+                    let data = this.buf.split().freeze();
+                    return Poll::Ready(Some(Ok(Frame::data(data))));
+                }
+            }
+        }
+
+        if this.buf.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Ready(Some(Ok(Frame::data(this.buf.split().freeze()))))
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.buf.is_empty() && self.held.is_none() && (self.finished || self.inner.is_end_stream())
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::with_exact(self.buf.len() as u64) + self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+    use crate::{BodyExt, StreamBody};
+
+    #[tokio::test]
+    async fn coalesces_small_frames() {
+        let frames =
+            ["ab", "cd", "ef", "gh", "i"].map(|s| Ok::<_, Infallible>(Frame::data(Bytes::from(s))));
+        let body = &mut StreamBody::new(futures_util::stream::iter(frames)).buffer(4);
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "abcd"
+        );
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "efgh"
+        );
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "i"
+        );
+        assert!(body.frame().await.is_none());
+        assert!(body.is_end_stream());
+    }
+
+    #[tokio::test]
+    async fn trailers_flush_pending_data() {
+        let mut trailers = http::HeaderMap::new();
+        trailers.insert("x-done", "1".parse().unwrap());
+        let frames = vec![
+            Frame::data(Bytes::from("a")),
+            Frame::data(Bytes::from("b")),
+            Frame::trailers(trailers),
+        ];
+        let body = &mut StreamBody::new(futures_util::stream::iter(
+            frames.into_iter().map(Ok::<_, Infallible>),
+        ))
+        .buffer(16);
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "ab"
+        );
+        assert_eq!(body.size_hint().lower(), 0);
+        let trailers = body
+            .frame()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_trailers()
+            .unwrap();
+        assert_eq!(trailers["x-done"], "1");
+        assert!(body.frame().await.is_none());
+    }
+}
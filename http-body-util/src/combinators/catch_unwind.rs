@@ -0,0 +1,158 @@
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    any::Any,
+    error::Error,
+    fmt,
+    panic::{catch_unwind, AssertUnwindSafe},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`catch_unwind`] combinator.
+    ///
+    /// [`catch_unwind`]: crate::BodyExt::catch_unwind
+    #[derive(Clone, Debug)]
+    pub struct CatchUnwind<B> {
+        #[pin]
+        inner: B,
+        panicked: bool,
+    }
+}
+
+impl<B> CatchUnwind<B> {
+    #[inline]
+    pub(crate) fn new(body: B) -> Self {
+        Self {
+            inner: body,
+            panicked: false,
+        }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for CatchUnwind<B>
+where
+    B: Body,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    type Data = B::Data;
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        if *this.panicked {
+            return Poll::Ready(None);
+        }
+
+        let inner = this.inner;
+        match catch_unwind(AssertUnwindSafe(|| inner.poll_frame(cx))) {
+            Ok(poll) => poll.map(|frame| frame.map(|res| res.map_err(Into::into))),
+            Err(payload) => {
+                *this.panicked = true;
+                Poll::Ready(Some(Err(BodyPanicked::new(payload).into())))
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.panicked || self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        if self.panicked {
+            SizeHint::with_exact(0)
+        } else {
+            self.inner.size_hint()
+        }
+    }
+}
+
+/// An error returned when a body panics while being polled.
+#[derive(Debug)]
+pub struct BodyPanicked {
+    message: Option<String>,
+}
+
+impl BodyPanicked {
+    fn new(payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => Some(*message),
+            Err(payload) => payload
+                .downcast_ref::<&'static str>()
+                .map(|message| (*message).to_owned()),
+        };
+        Self { message }
+    }
+
+    /// Returns the panic message, if the payload was a string.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl fmt::Display for BodyPanicked {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "body panicked: {}", message),
+            None => f.write_str("body panicked"),
+        }
+    }
+}
+
+impl Error for BodyPanicked {}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use futures_util::StreamExt;
+
+    use super::*;
+    use crate::{BodyExt, StreamBody};
+
+    #[tokio::test]
+    async fn panic_becomes_error() {
+        let stream = futures_util::stream::iter(0..2).map(|i| {
+            if i == 1 {
+                panic!("bad frame {}", i);
+            }
+            Ok::<_, Infallible>(Frame::data(Bytes::from("hello")))
+        });
+        let mut body = StreamBody::new(stream).catch_unwind();
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hello"
+        );
+        let err = body.frame().await.unwrap().unwrap_err();
+        let err = err.downcast_ref::<BodyPanicked>().unwrap();
+        assert_eq!(err.message(), Some("bad frame 1"));
+        assert!(body.is_end_stream());
+        assert!(body.frame().await.is_none());
+    }
+}
@@ -0,0 +1,170 @@
+use bytes::Buf;
+use futures_core::ready;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    error::Error,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`chain`] combinator.
+    ///
+    /// [`chain`]: crate::BodyExt::chain
+    #[derive(Clone, Copy, Debug)]
+    pub struct Chain<A, B> {
+        #[pin]
+        first: A,
+        #[pin]
+        second: B,
+        first_done: bool,
+    }
+}
+
+impl<A, B> Chain<A, B> {
+    #[inline]
+    pub(crate) fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            first_done: false,
+        }
+    }
+
+    /// Get references to the inner bodies
+    pub fn get_ref(&self) -> (&A, &B) {
+        (&self.first, &self.second)
+    }
+
+    /// Get mutable references to the inner bodies
+    pub fn get_mut(&mut self) -> (&mut A, &mut B) {
+        (&mut self.first, &mut self.second)
+    }
+
+    /// Consume `self`, returning the inner bodies
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A, B, Data> Body for Chain<A, B>
+where
+    A: Body<Data = Data>,
+    B: Body<Data = Data>,
+    A::Error: Into<Box<dyn Error + Send + Sync>>,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+    Data: Buf,
+{
+    type Data = Data;
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+
+        if !*this.first_done {
+            match ready!(this.first.poll_frame(cx)) {
+                Some(frame) => return Poll::Ready(Some(frame.map_err(Into::into))),
+                None => *this.first_done = true,
+            }
+        }
+
+        this.second
+            .poll_frame(cx)
+            .map(|poll| poll.map(|opt| opt.map_err(Into::into)))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        (self.first_done || self.first.is_end_stream()) && self.second.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        let second = self.second.size_hint();
+        if self.first_done {
+            return second;
+        }
+
+        self.first.size_hint() + second
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use http::HeaderMap;
+
+    use super::*;
+    use crate::{BodyExt, Empty, Full, StreamBody};
+
+    #[tokio::test]
+    async fn emits_first_then_second() {
+        let mut body = Full::<_, Infallible>::new(Bytes::from("hello "))
+            .chain(Full::<_, Infallible>::new(Bytes::from("world")));
+
+        assert_eq!(body.size_hint().exact(), Some(11));
+
+        let frame = body.frame().await.unwrap().unwrap();
+        assert_eq!(frame.into_data().unwrap(), "hello ");
+        assert!(!body.is_end_stream());
+        assert_eq!(body.size_hint().exact(), Some(5));
+
+        let frame = body.frame().await.unwrap().unwrap();
+        assert_eq!(frame.into_data().unwrap(), "world");
+        assert!(body.is_end_stream());
+
+        assert!(body.frame().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn forwards_first_trailers() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("foo", "bar".parse().unwrap());
+
+        let first = StreamBody::new(futures_util::stream::iter([
+            Ok::<_, Infallible>(Frame::data(Bytes::from("a"))),
+            Ok(Frame::trailers(trailers.clone())),
+        ]));
+        let mut body = first.chain(Full::<_, Infallible>::new(Bytes::from("b")));
+
+        let frame = body.frame().await.unwrap().unwrap();
+        assert_eq!(frame.into_data().unwrap(), "a");
+        let frame = body.frame().await.unwrap().unwrap();
+        assert_eq!(frame.into_trailers().unwrap(), trailers);
+        let frame = body.frame().await.unwrap().unwrap();
+        assert_eq!(frame.into_data().unwrap(), "b");
+        assert!(body.frame().await.is_none());
+    }
+
+    #[test]
+    fn end_stream_when_both_empty() {
+        let body = Empty::<Bytes, Infallible>::new().chain(Empty::<Bytes, Infallible>::new());
+        assert!(body.is_end_stream());
+        assert_eq!(body.size_hint().exact(), Some(0));
+    }
+
+    #[test]
+    fn unknown_upper_when_either_unknown() {
+        let first = StreamBody::new(futures_util::stream::empty::<
+            Result<Frame<Bytes>, Infallible>,
+        >());
+        let body = first.chain(Full::<_, Infallible>::new(Bytes::from("abc")));
+        assert_eq!(body.size_hint().lower(), 3);
+        assert_eq!(body.size_hint().upper(), None);
+    }
+
+    #[tokio::test]
+    async fn cloned_body_yields_same_frames() {
+        let template = Full::<_, Infallible>::new(Bytes::from("hello"))
+            .chain(Full::<_, Infallible>::new(Bytes::from(" world")));
+
+        for body in [template.clone(), template] {
+            let collected = body.collect().await.unwrap();
+            assert_eq!(collected.to_bytes(), "hello world");
+        }
+    }
+}
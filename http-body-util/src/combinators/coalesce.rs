@@ -0,0 +1,152 @@
+use bytes::Bytes;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::Collected;
+
+pin_project! {
+    /// Body returned by the [`coalesce`] combinator.
+    ///
+    /// [`coalesce`]: crate::BodyExt::coalesce
+    #[derive(Debug)]
+    pub struct Coalesce<B: Body> {
+        #[pin]
+        inner: B,
+        collected: Collected<B::Data>,
+        output: Option<Collected<Bytes>>,
+    }
+}
+
+impl<B: Body> Coalesce<B> {
+    #[inline]
+    pub(crate) fn new(body: B) -> Self {
+        Self {
+            inner: body,
+            collected: Collected::default(),
+            output: None,
+        }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+}
+
+impl<B> Body for Coalesce<B>
+where
+    B: Body,
+{
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(output) = this.output {
+                return Pin::new(output)
+                    .poll_frame(cx)
+                    .map(|frame| frame.map(|res| Ok(res.unwrap_or_else(|never| match never {}))));
+            }
+
+            match this.inner.as_mut().poll_frame(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Some(Ok(frame))) => this.collected.push_frame(frame),
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(None) => {
+                    let collected = std::mem::take(this.collected);
+                    *this.output = Some(collected.into_body());
+                }
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        match &self.output {
+            Some(output) => output.is_end_stream(),
+            None => false,
+        }
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        match &self.output {
+            Some(output) => output.size_hint(),
+            None => {
+                let buffered = self.collected.size_hint();
+                buffered + self.inner.size_hint()
+            }
+        }
+    }
+}
+
+impl<B> Clone for Coalesce<B>
+where
+    B: Body + Clone,
+    B::Data: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            collected: self.collected.clone(),
+            output: self.output.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use http::HeaderMap;
+
+    use super::*;
+    use crate::{BodyExt, StreamBody};
+
+    #[tokio::test]
+    async fn emits_one_data_frame_then_trailers() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("x-foo", "bar".parse().unwrap());
+        let frames = [
+            Frame::data(Bytes::from("hello")),
+            Frame::data(Bytes::from(" ")),
+            Frame::data(Bytes::from("world")),
+            Frame::trailers(trailers),
+        ];
+        let mut body =
+            StreamBody::new(futures_util::stream::iter(frames.map(Ok::<_, Infallible>))).coalesce();
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hello world"
+        );
+        assert_eq!(body.size_hint().exact(), Some(0));
+        let trailers = body
+            .frame()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_trailers()
+            .unwrap();
+        assert_eq!(trailers["x-foo"], "bar");
+        assert!(body.frame().await.is_none());
+        assert!(body.is_end_stream());
+    }
+}
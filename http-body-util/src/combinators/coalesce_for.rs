@@ -0,0 +1,217 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use tokio::time::{sleep, Sleep};
+
+pin_project! {
+    /// Body returned by the [`coalesce_for`] combinator.
+    ///
+    /// [`coalesce_for`]: crate::BodyExt::coalesce_for
+    #[derive(Debug)]
+    pub struct CoalesceFor<B> {
+        #[pin]
+        inner: B,
+        #[pin]
+        sleep: Option<Sleep>,
+        window: Duration,
+        buf: BytesMut,
+        held: Option<Frame<Bytes>>,
+        finished: bool,
+    }
+}
+
+impl<B> CoalesceFor<B> {
+    #[inline]
+    pub(crate) fn new(body: B, window: Duration) -> Self {
+        Self {
+            inner: body,
+            sleep: None,
+            window,
+            buf: BytesMut::new(),
+            held: None,
+            finished: false,
+        }
+    }
+
+    /// Returns the number of bytes buffered but not yet yielded.
+    pub fn buffered(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+}
+
+impl<B> Body for CoalesceFor<B>
+where
+    B: Body,
+{
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        if let Some(frame) = this.held.take() {
+            return Poll::Ready(Some(Ok(frame)));
+        }
+
+        while !*this.finished {
+            let frame = match this.inner.as_mut().poll_frame(cx) {
+                Poll::Pending => None,
+                Poll::Ready(Some(Ok(frame))) => Some(frame),
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(None) => {
+                    *this.finished = true;
+                    break;
+                }
+            };
+            let pending = frame.is_none();
+
+            if let Some(frame) = frame {
+                match frame.into_data() {
+                    Ok(data) => {
+                        if !data.has_remaining() {
+                            continue;
+                        }
+                        if this.sleep.is_none() {
+                            this.sleep.set(Some(sleep(*this.window)));
+                        }
+                        this.buf.put(data);
+                    }
+                    Err(frame) => {
+                        // Any frame other than DATA flushes the buffer, and is yielded after it.
+                        let frame = frame.map_data(|mut data| data.copy_to_bytes(data.remaining()));
+                        if this.buf.is_empty() {
+                            return Poll::Ready(Some(Ok(frame)));
+                        }
+                        *this.held = Some(frame);
+                        this.sleep.set(None);
+                        let data = this.buf.split().freeze();
+                        return Poll::Ready(Some(Ok(Frame::data(data))));
+                    }
+                }
+            }
+
+            // The window is checked even while the inner body keeps yielding frames, so a body
+            // that is always ready does not hold data back indefinitely.
+            let elapsed = match this.sleep.as_mut().as_pin_mut() {
+                Some(sleep) => sleep.poll(cx).is_ready(),
+                None => false,
+            };
+            if elapsed {
+                this.sleep.set(None);
+                let data = this.buf.split().freeze();
+                return Poll::Ready(Some(Ok(Frame::data(data))));
+            }
+            if pending {
+                return Poll::Pending;
+            }
+        }
+
+        this.sleep.set(None);
+        if this.buf.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Ready(Some(Ok(Frame::data(this.buf.split().freeze()))))
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.buf.is_empty() && self.held.is_none() && (self.finished || self.inner.is_end_stream())
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::with_exact(self.buf.len() as u64) + self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use futures_util::StreamExt;
+    use tokio::time::Instant;
+
+    use super::*;
+    use crate::{BodyExt, StreamBody};
+
+    #[tokio::test(start_paused = true)]
+    async fn batches_frames_within_window() {
+        let frames = Box::pin(futures_util::stream::iter(["a", "b", "c", "d"]).then(
+            |s| async move {
+                tokio::time::sleep(Duration::from_millis(40)).await;
+                Ok::<_, Infallible>(Frame::data(Bytes::from(s)))
+            },
+        ));
+        let start = Instant::now();
+        let mut body = Box::pin(StreamBody::new(frames).coalesce_for(Duration::from_millis(100)));
+
+        // "a" arrives at 40ms and opens the window, which closes at 140ms.
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "abc"
+        );
+        assert_eq!(start.elapsed(), Duration::from_millis(140));
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "d"
+        );
+        assert!(body.frame().await.is_none());
+        assert!(body.is_end_stream());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn trailers_flush_pending_data() {
+        let mut trailers = http::HeaderMap::new();
+        trailers.insert("x-done", "1".parse().unwrap());
+        let frames = vec![
+            Frame::data(Bytes::from("a")),
+            Frame::data(Bytes::from("b")),
+            Frame::trailers(trailers),
+        ];
+        let body = StreamBody::new(futures_util::stream::iter(
+            frames.into_iter().map(Ok::<_, Infallible>),
+        ));
+        let mut body = Box::pin(body.coalesce_for(Duration::from_secs(1)));
+        assert_eq!(body.size_hint().lower(), 0);
+
+        let start = Instant::now();
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "ab"
+        );
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        let trailers = body
+            .frame()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_trailers()
+            .unwrap();
+        assert_eq!(trailers["x-done"], "1");
+        assert!(body.frame().await.is_none());
+    }
+}
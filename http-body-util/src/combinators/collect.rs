@@ -1,10 +1,14 @@
 use std::{
+    error::Error,
+    fmt,
     future::Future,
     pin::Pin,
     task::{Context, Poll},
 };
 
+use bytes::{Buf, BufMut, BytesMut};
 use futures_core::ready;
+use http::HeaderMap;
 use http_body::Body;
 use pin_project_lite::pin_project;
 
@@ -42,3 +46,282 @@ impl<T: Body + ?Sized> Future for Collect<T> {
         }
     }
 }
+
+pin_project! {
+    /// Future that appends the data of a body to a buffer and resolves into its trailers.
+    ///
+    /// See [`BodyExt::collect_into`] for more details.
+    ///
+    /// [`BodyExt::collect_into`]: crate::BodyExt::collect_into
+    pub struct CollectInto<'a, T>
+    where
+        T: Body,
+        T: ?Sized,
+    {
+        pub(crate) buf: &'a mut BytesMut,
+        pub(crate) trailers: Option<HeaderMap>,
+        #[pin]
+        pub(crate) body: T,
+    }
+}
+
+impl<'a, T: Body + ?Sized> Future for CollectInto<'a, T> {
+    type Output = Result<Option<HeaderMap>, T::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> std::task::Poll<Self::Output> {
+        let mut me = self.project();
+
+        loop {
+            let frame = ready!(me.body.as_mut().poll_frame(cx));
+
+            let frame = if let Some(frame) = frame {
+                frame?
+            } else {
+                return Poll::Ready(Ok(me.trailers.take()));
+            };
+
+            let frame = match frame.into_data() {
+                Ok(data) => {
+                    me.buf.put(data);
+                    continue;
+                }
+                Err(frame) => frame,
+            };
+
+            if let Ok(trailers) = frame.into_trailers() {
+                if let Some(current) = me.trailers {
+                    current.extend(trailers);
+                } else {
+                    *me.trailers = Some(trailers);
+                }
+            }
+        }
+    }
+}
+
+pin_project! {
+    /// Future that resolves into a [`Collected`], unless the body exceeds a length limit.
+    ///
+    /// See [`BodyExt::collect_limited`] for more details.
+    ///
+    /// [`Collected`]: crate::Collected
+    /// [`BodyExt::collect_limited`]: crate::BodyExt::collect_limited
+    pub struct CollectLimited<T>
+    where
+        T: Body,
+        T: ?Sized,
+    {
+        pub(crate) collected: Option<crate::Collected<T::Data>>,
+        pub(crate) remaining: usize,
+        pub(crate) limit: usize,
+        pub(crate) trailers_remaining: usize,
+        pub(crate) trailers_limit: usize,
+        #[pin]
+        pub(crate) body: T,
+    }
+}
+
+impl<T> Future for CollectLimited<T>
+where
+    T: Body + ?Sized,
+    T::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    type Output = Result<crate::Collected<T::Data>, Box<dyn Error + Send + Sync>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> std::task::Poll<Self::Output> {
+        let mut me = self.project();
+
+        loop {
+            let frame = ready!(me.body.as_mut().poll_frame(cx));
+
+            let frame = if let Some(frame) = frame {
+                frame.map_err(Into::into)?
+            } else {
+                return Poll::Ready(Ok(me.collected.take().expect("polled after complete")));
+            };
+
+            if let Some(data) = frame.data_ref() {
+                if data.remaining() > *me.remaining {
+                    *me.remaining = 0;
+                    return Poll::Ready(Err(CollectLimitError { limit: *me.limit }.into()));
+                }
+                *me.remaining -= data.remaining();
+            }
+
+            if let Some(trailers) = frame.trailers_ref() {
+                let len = trailers
+                    .iter()
+                    .map(|(name, value)| name.as_str().len() + value.len())
+                    .sum::<usize>();
+                if len > *me.trailers_remaining {
+                    *me.trailers_remaining = 0;
+                    return Poll::Ready(Err(TrailersLimitError {
+                        limit: *me.trailers_limit,
+                    }
+                    .into()));
+                }
+                *me.trailers_remaining -= len;
+            }
+
+            me.collected.as_mut().unwrap().push_frame(frame);
+        }
+    }
+}
+
+/// Options for [`BodyExt::collect_with_opts`].
+///
+/// By default, no limits are applied.
+///
+/// [`BodyExt::collect_with_opts`]: crate::BodyExt::collect_with_opts
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CollectOpts {
+    pub(crate) max_data: Option<usize>,
+    pub(crate) max_trailers_bytes: Option<usize>,
+}
+
+impl CollectOpts {
+    /// Create a new `CollectOpts` with no limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of data bytes to collect.
+    ///
+    /// Exceeding it fails with a [`CollectLimitError`].
+    pub fn max_data(mut self, limit: usize) -> Self {
+        self.max_data = Some(limit);
+        self
+    }
+
+    /// Set the maximum total size of the trailers to collect.
+    ///
+    /// The size of the trailers is the sum of the lengths of every header name and value.
+    /// Exceeding it fails with a [`TrailersLimitError`].
+    pub fn max_trailers_bytes(mut self, limit: usize) -> Self {
+        self.max_trailers_bytes = Some(limit);
+        self
+    }
+}
+
+/// An error returned when a collected body exceeds the configured limit.
+#[derive(Debug)]
+pub struct CollectLimitError {
+    limit: usize,
+}
+
+impl CollectLimitError {
+    /// Returns the limit that was exceeded.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl fmt::Display for CollectLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "collected body exceeded the limit of {} bytes",
+            self.limit
+        )
+    }
+}
+
+impl Error for CollectLimitError {}
+
+/// An error returned when the trailers of a collected body exceed the configured limit.
+#[derive(Debug)]
+pub struct TrailersLimitError {
+    limit: usize,
+}
+
+impl TrailersLimitError {
+    /// Returns the limit that was exceeded.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl fmt::Display for TrailersLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "collected trailers exceeded the limit of {} bytes",
+            self.limit
+        )
+    }
+}
+
+impl Error for TrailersLimitError {}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use http_body::Frame;
+
+    use super::*;
+    use crate::{BodyExt, Full, StreamBody};
+
+    #[tokio::test]
+    async fn collect_limited_under_limit() {
+        let bufs = [Bytes::from("hello"), Bytes::from("world")];
+        let body = StreamBody::new(futures_util::stream::iter(
+            bufs.map(Frame::data).map(Ok::<_, Infallible>),
+        ));
+
+        let collected = body.collect_limited(10).await.unwrap();
+        assert_eq!(collected.to_bytes(), "helloworld");
+    }
+
+    #[tokio::test]
+    async fn collect_limited_over_limit() {
+        let bufs = [Bytes::from("hello"), Bytes::from("world!")];
+        let body = StreamBody::new(futures_util::stream::iter(
+            bufs.map(Frame::data).map(Ok::<_, Infallible>),
+        ));
+
+        let err = body.collect_limited(10).await.unwrap_err();
+        let err = err.downcast_ref::<CollectLimitError>().unwrap();
+        assert_eq!(err.limit(), 10);
+    }
+
+    #[tokio::test]
+    async fn collect_into_appends_to_buffer() {
+        let mut buf = BytesMut::from("prefix ");
+        let body = Full::<_, Infallible>::with_trailers(Bytes::from("hello"), HeaderMap::new());
+
+        let trailers = body.collect_into(&mut buf).await.unwrap();
+        assert!(trailers.is_some());
+        assert_eq!(buf, "prefix hello");
+
+        buf.clear();
+        let body = Full::<_, Infallible>::new(Bytes::from("world"));
+        assert!(body.collect_into(&mut buf).await.unwrap().is_none());
+        assert_eq!(buf, "world");
+    }
+
+    #[tokio::test]
+    async fn collect_with_opts_trailers_over_limit() {
+        let mut trailers = http::HeaderMap::new();
+        trailers.insert("x-large", "a".repeat(32).parse().unwrap());
+        let body = Full::<_, Infallible>::with_trailers(Bytes::from("hello"), trailers);
+
+        let opts = CollectOpts::new().max_data(10).max_trailers_bytes(16);
+        let err = body.collect_with_opts(opts).await.unwrap_err();
+        let err = err.downcast_ref::<TrailersLimitError>().unwrap();
+        assert_eq!(err.limit(), 16);
+    }
+
+    #[tokio::test]
+    async fn collect_with_opts_under_limits() {
+        let mut trailers = http::HeaderMap::new();
+        trailers.insert("x-small", "a".parse().unwrap());
+        let body = Full::<_, Infallible>::with_trailers(Bytes::from("hello"), trailers);
+
+        let opts = CollectOpts::new().max_data(5).max_trailers_bytes(8);
+        let collected = body.collect_with_opts(opts).await.unwrap();
+        assert_eq!(collected.trailers().unwrap()["x-small"], "a");
+        assert_eq!(collected.to_bytes(), "hello");
+    }
+}
@@ -0,0 +1,433 @@
+use futures_core::{ready, Stream};
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    collections::VecDeque,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// A body that yields the frames of each body produced by an iterator, in order.
+    ///
+    /// Each body is polled to completion before the next one is taken from the iterator. If a
+    /// body yields an error, that error is returned and the concatenation ends.
+    ///
+    /// Frames are passed through as-is, so trailers yielded by any of the bodies are yielded
+    /// too.
+    ///
+    /// Bodies that have not been taken from the iterator yet are unknown, so the upper bound of
+    /// the size hint is only known once the iterator reports that it is exhausted. Use
+    /// [`Concat::new_exact`] to sum the size hints of all the bodies up front.
+    pub struct Concat<I: Iterator> {
+        iter: I,
+        pending: VecDeque<I::Item>,
+        #[pin]
+        current: Option<I::Item>,
+        done: bool,
+    }
+}
+
+impl<I: Iterator> Concat<I> {
+    /// Create a new `Concat` from the given bodies.
+    pub fn new<T>(bodies: T) -> Self
+    where
+        T: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            iter: bodies.into_iter(),
+            pending: VecDeque::new(),
+            current: None,
+            done: false,
+        }
+    }
+
+    /// Create a new `Concat` from an [`ExactSizeIterator`] of bodies.
+    ///
+    /// All the bodies are taken from the iterator up front, so the size hint is the sum of the
+    /// size hints of the remaining bodies, like [`ConcatAll`], but the bodies do not need to be
+    /// [`Unpin`].
+    pub fn new_exact<T>(bodies: T) -> Self
+    where
+        T: IntoIterator<IntoIter = I>,
+        I: ExactSizeIterator,
+    {
+        let mut iter = bodies.into_iter();
+        let mut pending = VecDeque::with_capacity(iter.len());
+        pending.extend(iter.by_ref());
+        Self {
+            iter,
+            pending,
+            current: None,
+            done: false,
+        }
+    }
+
+    fn iter_is_empty(&self) -> bool {
+        self.iter.size_hint().1 == Some(0)
+    }
+}
+
+impl<I> Body for Concat<I>
+where
+    I: Iterator,
+    I::Item: Body,
+{
+    type Data = <I::Item as Body>::Data;
+    type Error = <I::Item as Body>::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        loop {
+            if *this.done {
+                return Poll::Ready(None);
+            }
+
+            if this.current.is_none() {
+                match this.pending.pop_front().or_else(|| this.iter.next()) {
+                    Some(body) => this.current.set(Some(body)),
+                    None => {
+                        *this.done = true;
+                        return Poll::Ready(None);
+                    }
+                }
+            }
+
+            let body = this.current.as_mut().as_pin_mut().unwrap();
+            match ready!(body.poll_frame(cx)) {
+                Some(Ok(frame)) => return Poll::Ready(Some(Ok(frame))),
+                Some(Err(err)) => {
+                    this.current.set(None);
+                    this.pending.clear();
+                    *this.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+                None => this.current.set(None),
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.done
+            || (self.iter_is_empty()
+                && self.pending.iter().all(|body| body.is_end_stream())
+                && self
+                    .current
+                    .as_ref()
+                    .map_or(true, |body| body.is_end_stream()))
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        if self.done {
+            return SizeHint::with_exact(0);
+        }
+
+        let known = self
+            .pending
+            .iter()
+            .chain(self.current.as_ref())
+            .fold(SizeHint::with_exact(0), |hint, body| {
+                hint + body.size_hint()
+            });
+
+        if self.iter_is_empty() {
+            known
+        } else {
+            let mut hint = SizeHint::new();
+            hint.set_lower(known.lower());
+            hint
+        }
+    }
+}
+
+impl<I> fmt::Debug for Concat<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Concat")
+            .field("iter", &self.iter)
+            .field("pending", &self.pending)
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+/// A body that yields the frames of each body in a list, in order.
+///
+/// This behaves like [`Concat`], except that all the bodies are known up front, so the size
+/// hint is the sum of the size hints of the remaining bodies. The bodies must be [`Unpin`];
+/// use [`Box::pin`] for those that are not.
+#[derive(Clone, Debug)]
+pub struct ConcatAll<B> {
+    bodies: VecDeque<B>,
+    done: bool,
+}
+
+impl<B> ConcatAll<B> {
+    /// Create a new `ConcatAll` from the given bodies.
+    pub fn new(bodies: Vec<B>) -> Self {
+        Self {
+            bodies: bodies.into(),
+            done: false,
+        }
+    }
+}
+
+impl<B> Body for ConcatAll<B>
+where
+    B: Body + Unpin,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        loop {
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            let body = match self.bodies.front_mut() {
+                Some(body) => body,
+                None => {
+                    self.done = true;
+                    return Poll::Ready(None);
+                }
+            };
+            match ready!(Pin::new(body).poll_frame(cx)) {
+                Some(Ok(frame)) => return Poll::Ready(Some(Ok(frame))),
+                Some(Err(err)) => {
+                    self.bodies.clear();
+                    self.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+                None => {
+                    self.bodies.pop_front();
+                }
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.done || self.bodies.iter().all(|body| body.is_end_stream())
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.bodies
+            .iter()
+            .fold(SizeHint::with_exact(0), |hint, body| {
+                hint + body.size_hint()
+            })
+    }
+}
+
+pin_project! {
+    /// A body that yields the frames of each body produced by a [`Stream`], in order.
+    ///
+    /// This behaves like [`Concat`], except that the bodies are produced asynchronously.
+    pub struct ConcatStream<S: Stream> {
+        #[pin]
+        stream: S,
+        #[pin]
+        current: Option<S::Item>,
+        done: bool,
+    }
+}
+
+impl<S: Stream> ConcatStream<S> {
+    /// Create a new `ConcatStream` from the given stream of bodies.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            current: None,
+            done: false,
+        }
+    }
+}
+
+impl<S> Body for ConcatStream<S>
+where
+    S: Stream,
+    S::Item: Body,
+{
+    type Data = <S::Item as Body>::Data;
+    type Error = <S::Item as Body>::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        loop {
+            if *this.done {
+                return Poll::Ready(None);
+            }
+
+            if this.current.is_none() {
+                match ready!(this.stream.as_mut().poll_next(cx)) {
+                    Some(body) => this.current.set(Some(body)),
+                    None => {
+                        *this.done = true;
+                        return Poll::Ready(None);
+                    }
+                }
+            }
+
+            let body = this.current.as_mut().as_pin_mut().unwrap();
+            match ready!(body.poll_frame(cx)) {
+                Some(Ok(frame)) => return Poll::Ready(Some(Ok(frame))),
+                Some(Err(err)) => {
+                    this.current.set(None);
+                    *this.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+                None => this.current.set(None),
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.done
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        if self.done {
+            return SizeHint::with_exact(0);
+        }
+
+        let mut hint = SizeHint::new();
+        if let Some(body) = &self.current {
+            hint.set_lower(body.size_hint().lower());
+        }
+        hint
+    }
+}
+
+impl<S> fmt::Debug for ConcatStream<S>
+where
+    S: Stream + fmt::Debug,
+    S::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ConcatStream")
+            .field("stream", &self.stream)
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, fmt};
+
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::{BodyExt, Full};
+
+    #[derive(Debug)]
+    struct Boom;
+
+    impl fmt::Display for Boom {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("boom")
+        }
+    }
+
+    impl Error for Boom {}
+
+    #[tokio::test]
+    async fn yields_bodies_in_order() {
+        let parts = ["a", "b", "c"].map(|s| Full::<_, Boom>::new(Bytes::from(s)));
+        let mut body = Concat::new(parts);
+        assert_eq!(body.size_hint().upper(), None);
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "a"
+        );
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "b"
+        );
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "c"
+        );
+        assert!(body.is_end_stream());
+        assert_eq!(body.size_hint().exact(), Some(0));
+        assert!(body.frame().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn new_exact_sums_size_hints() {
+        let parts = ["hello", " ", "world"].map(|s| Full::<_, Boom>::new(Bytes::from(s)));
+        let mut body = Concat::new_exact(parts);
+        assert_eq!(body.size_hint().exact(), Some(11));
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hello"
+        );
+        assert_eq!(body.size_hint().exact(), Some(6));
+
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), " world");
+    }
+
+    #[tokio::test]
+    async fn error_ends_concatenation() {
+        let part = |item: Result<&'static str, Boom>| {
+            crate::StreamBody::new(futures_util::stream::iter(vec![
+                item.map(|s| Frame::data(Bytes::from(s)))
+            ]))
+        };
+        let mut body = Concat::new(vec![part(Ok("a")), part(Err(Boom)), part(Ok("b"))]);
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "a"
+        );
+        assert!(body.frame().await.unwrap().is_err());
+        assert!(body.is_end_stream());
+        assert!(body.frame().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn concat_all_sums_size_hints() {
+        let parts = vec!["hello", " ", "world"]
+            .into_iter()
+            .map(|s| Full::<_, Boom>::new(Bytes::from(s)))
+            .collect();
+        let mut body = ConcatAll::new(parts);
+        assert_eq!(body.size_hint().exact(), Some(11));
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hello"
+        );
+        assert_eq!(body.size_hint().exact(), Some(6));
+
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), " world");
+    }
+
+    #[tokio::test]
+    async fn concat_stream() {
+        let parts = ["hello", " ", "world"].map(|s| Full::<_, Boom>::new(Bytes::from(s)));
+        let body = ConcatStream::new(futures_util::stream::iter(parts));
+
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), "hello world");
+    }
+}
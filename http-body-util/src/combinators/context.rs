@@ -0,0 +1,139 @@
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    error::Error,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`context`] combinator.
+    ///
+    /// [`context`]: crate::BodyExt::context
+    #[derive(Clone, Copy, Debug)]
+    pub struct WithContext<B> {
+        #[pin]
+        inner: B,
+        label: &'static str,
+    }
+}
+
+impl<B> WithContext<B> {
+    #[inline]
+    pub(crate) fn new(body: B, label: &'static str) -> Self {
+        Self { inner: body, label }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for WithContext<B>
+where
+    B: Body,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    type Data = B::Data;
+    type Error = ContextError;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        match this.inner.poll_frame(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Ready(Some(Ok(frame))) => Poll::Ready(Some(Ok(frame))),
+            Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(ContextError {
+                label: this.label,
+                source: err.into(),
+            }))),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// An error returned by [`WithContext`], labelling the body that produced it.
+///
+/// The original error is available through [`Error::source`].
+#[derive(Debug)]
+pub struct ContextError {
+    label: &'static str,
+    source: Box<dyn Error + Send + Sync>,
+}
+
+impl ContextError {
+    /// Returns the label given to [`context`](crate::BodyExt::context).
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// Consume `self`, returning the original error
+    pub fn into_source(self) -> Box<dyn Error + Send + Sync> {
+        self.source
+    }
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "body error in {}: {}", self.label, self.source)
+    }
+}
+
+impl Error for ContextError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, io};
+
+    use bytes::Bytes;
+
+    use crate::{BodyExt, StreamBody};
+
+    #[tokio::test]
+    async fn labels_errors() {
+        let frames = vec![
+            Ok(http_body::Frame::data(Bytes::from("hello"))),
+            Err(io::Error::new(io::ErrorKind::Other, "oh no")),
+        ];
+        let mut body = StreamBody::new(futures_util::stream::iter(frames)).context("upstream");
+
+        let data = body.frame().await.unwrap().unwrap().into_data().unwrap();
+        assert_eq!(data, "hello");
+
+        let err = body.frame().await.unwrap().unwrap_err();
+        assert_eq!(err.label(), "upstream");
+        assert_eq!(err.to_string(), "body error in upstream: oh no");
+        assert!(err.source().unwrap().is::<io::Error>());
+    }
+}
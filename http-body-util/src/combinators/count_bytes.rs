@@ -0,0 +1,114 @@
+use bytes::Buf;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`count_bytes`] combinator.
+    ///
+    /// [`count_bytes`]: crate::BodyExt::count_bytes
+    #[derive(Debug)]
+    pub struct CountingBody<B> {
+        #[pin]
+        inner: B,
+        counter: Arc<AtomicU64>,
+    }
+}
+
+impl<B> CountingBody<B> {
+    #[inline]
+    pub(crate) fn new(body: B) -> (Self, Arc<AtomicU64>) {
+        let counter = Arc::new(AtomicU64::new(0));
+        let body = Self {
+            inner: body,
+            counter: counter.clone(),
+        };
+        (body, counter)
+    }
+
+    /// Returns the number of data bytes yielded so far.
+    pub fn bytes_sent(&self) -> u64 {
+        self.counter.load(Ordering::Relaxed)
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for CountingBody<B>
+where
+    B: Body,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        let res = this.inner.poll_frame(cx);
+        if let Poll::Ready(Some(Ok(frame))) = &res {
+            if let Some(data) = frame.data_ref() {
+                this.counter
+                    .fetch_add(data.remaining() as u64, Ordering::Relaxed);
+            }
+        }
+        res
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::{BodyExt, StreamBody};
+
+    #[tokio::test]
+    async fn counts_data_bytes() {
+        let frames = ["hello", " world"].map(|s| Ok::<_, Infallible>(Frame::data(Bytes::from(s))));
+        let (mut body, counter) = StreamBody::new(futures_util::stream::iter(frames)).count_bytes();
+
+        body.frame().await.unwrap().unwrap();
+        assert_eq!(body.bytes_sent(), 5);
+
+        // Dropping the body early keeps the count so far.
+        drop(body);
+        assert_eq!(counter.load(Ordering::Relaxed), 5);
+    }
+}
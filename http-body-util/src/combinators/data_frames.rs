@@ -0,0 +1,193 @@
+use http::HeaderMap;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::util::{oneshot, Receiver, Sender};
+
+pin_project! {
+    /// A body that yields only the data frames of the inner body, handing its trailers to a
+    /// separate [`Trailers`] future.
+    #[derive(Debug)]
+    pub struct DataFrames<B> {
+        #[pin]
+        inner: B,
+        tx: Option<Sender<HeaderMap>>,
+    }
+}
+
+impl<B> DataFrames<B> {
+    #[inline]
+    pub(crate) fn new(body: B) -> (Self, Trailers) {
+        let (tx, rx) = oneshot();
+        let body = Self {
+            inner: body,
+            tx: Some(tx),
+        };
+        (body, Trailers { rx })
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+}
+
+impl<B> Body for DataFrames<B>
+where
+    B: Body,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        loop {
+            let frame = match this.inner.as_mut().poll_frame(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Some(Ok(frame))) => frame,
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(None) => {
+                    // Dropping the sender resolves the `Trailers` future.
+                    this.tx.take();
+                    return Poll::Ready(None);
+                }
+            };
+
+            match frame.into_trailers() {
+                Ok(trailers) => {
+                    if let Some(tx) = this.tx {
+                        tx.update(|current| match current {
+                            Some(current) => current.extend(trailers),
+                            None => *current = Some(trailers),
+                        });
+                    }
+                }
+                Err(frame) => return Poll::Ready(Some(Ok(frame))),
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.tx.is_none() || self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// Future that resolves to the trailers of a body split by [`split_frames`] or
+/// [`into_data_stream_with_trailers`].
+///
+/// The future resolves once the data side has reached the end of the body, or has been
+/// dropped. If the body yielded several trailers frames, they are merged into one map, with
+/// later entries replacing earlier entries of the same name. It resolves to `None` if the body
+/// had no trailers, or if the data side was dropped before reaching them.
+///
+/// [`split_frames`]: crate::BodyExt::split_frames
+/// [`into_data_stream_with_trailers`]: crate::BodyExt::into_data_stream_with_trailers
+#[must_use = "futures don't do anything unless polled"]
+pub struct Trailers {
+    rx: Receiver<HeaderMap>,
+}
+
+impl Future for Trailers {
+    type Output = Option<HeaderMap>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl fmt::Debug for Trailers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Trailers").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use futures_util::StreamExt;
+
+    use crate::{BodyExt, Full, StreamBody};
+
+    #[tokio::test]
+    async fn trailers_resolve_after_data() {
+        let mut trailers = http::HeaderMap::new();
+        trailers.insert("x-checksum", "abc".parse().unwrap());
+
+        let body = Full::<_, Infallible>::with_trailers(Bytes::from("hello"), trailers);
+        let (stream, trailers) = body.into_data_stream_with_trailers();
+
+        let data: Vec<_> = stream.map(Result::unwrap).collect().await;
+        assert_eq!(data, [Bytes::from("hello")]);
+        assert_eq!(trailers.await.unwrap()["x-checksum"], "abc");
+    }
+
+    #[tokio::test]
+    async fn split_frames_in_separate_tasks() {
+        let mut trailers = http::HeaderMap::new();
+        trailers.insert("x-checksum", "abc".parse().unwrap());
+
+        let body = Full::<_, Infallible>::with_trailers(Bytes::from("hello"), trailers);
+        let (data, trailers) = body.split_frames();
+
+        let trailers = tokio::spawn(trailers);
+        let collected = data.collect().await.unwrap();
+        assert!(collected.trailers().is_none());
+        assert_eq!(collected.to_bytes(), "hello");
+        assert_eq!(trailers.await.unwrap().unwrap()["x-checksum"], "abc");
+    }
+
+    #[tokio::test]
+    async fn merges_later_trailers() {
+        let trailers = |name: &'static str| {
+            let mut trailers = http::HeaderMap::new();
+            trailers.insert(name, "1".parse().unwrap());
+            http_body::Frame::<Bytes>::trailers(trailers)
+        };
+        let frames = [trailers("x-first"), trailers("x-second")].map(Ok::<_, Infallible>);
+        let body = StreamBody::new(futures_util::stream::iter(frames));
+        let (data, trailers) = body.split_frames();
+
+        assert!(data.collect().await.unwrap().to_bytes().is_empty());
+        let trailers = trailers.await.unwrap();
+        assert_eq!(trailers["x-first"], "1");
+        assert_eq!(trailers["x-second"], "1");
+    }
+
+    #[tokio::test]
+    async fn dropped_stream_resolves_to_none() {
+        let frames = [Ok::<_, Infallible>(http_body::Frame::data(Bytes::from(
+            "hello",
+        )))];
+        let body = StreamBody::new(futures_util::stream::iter(frames));
+        let (stream, trailers) = body.into_data_stream_with_trailers();
+
+        drop(stream);
+        assert!(trailers.await.is_none());
+    }
+}
@@ -0,0 +1,165 @@
+use std::{
+    error::Error,
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use tokio::time::{sleep_until, Instant, Sleep};
+
+pin_project! {
+    /// Body returned by the [`deadline`] combinator.
+    ///
+    /// [`deadline`]: crate::BodyExt::deadline
+    #[derive(Debug)]
+    pub struct Deadline<B> {
+        #[pin]
+        inner: B,
+        #[pin]
+        sleep: Option<Sleep>,
+        at: Instant,
+        finished: bool,
+    }
+}
+
+impl<B> Deadline<B> {
+    #[inline]
+    pub(crate) fn new(body: B, at: Instant) -> Self {
+        Self {
+            inner: body,
+            sleep: None,
+            at,
+            finished: false,
+        }
+    }
+
+    /// Returns the instant at which the body stops yielding frames.
+    pub fn deadline(&self) -> Instant {
+        self.at
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for Deadline<B>
+where
+    B: Body,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    type Data = B::Data;
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        if *this.finished {
+            return Poll::Ready(None);
+        }
+
+        if this.sleep.is_none() {
+            this.sleep.set(Some(sleep_until(*this.at)));
+        }
+        if this.sleep.as_pin_mut().unwrap().poll(cx).is_ready() {
+            return Poll::Ready(Some(Err(DeadlineExceeded { _priv: () }.into())));
+        }
+
+        match this.inner.poll_frame(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => {
+                *this.finished = true;
+                Poll::Ready(None)
+            }
+            Poll::Ready(Some(res)) => Poll::Ready(Some(res.map_err(Into::into))),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.finished || self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// An error returned when a body is still being read after its deadline has passed.
+///
+/// This is distinct from [`BodyTimeoutError`](super::BodyTimeoutError), which is returned when
+/// a body is inactive for too long.
+#[derive(Debug)]
+pub struct DeadlineExceeded {
+    _priv: (),
+}
+
+impl fmt::Display for DeadlineExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("body deadline exceeded")
+    }
+}
+
+impl Error for DeadlineExceeded {}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::Infallible, time::Duration};
+
+    use bytes::Bytes;
+    use futures_util::StreamExt;
+
+    use super::*;
+    use crate::{BodyExt, Full, StreamBody};
+
+    #[tokio::test(start_paused = true)]
+    async fn trickling_body_hits_deadline() {
+        let stream = futures_util::stream::repeat(()).then(|_| async {
+            tokio::time::sleep(Duration::from_millis(400)).await;
+            Ok::<_, Infallible>(Frame::data(Bytes::from("a")))
+        });
+        let at = Instant::now() + Duration::from_secs(1);
+        let mut body = Box::pin(StreamBody::new(Box::pin(stream)).deadline(at));
+
+        assert!(body.frame().await.unwrap().is_ok());
+        assert!(body.frame().await.unwrap().is_ok());
+        let err = body.frame().await.unwrap().unwrap_err();
+        assert!(err.is::<DeadlineExceeded>());
+        let err = body.frame().await.unwrap().unwrap_err();
+        assert!(err.is::<DeadlineExceeded>());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn finished_body_never_fires() {
+        let at = Instant::now() + Duration::from_secs(1);
+        let mut body = Box::pin(Full::<_, Infallible>::new(Bytes::from("hello")).deadline(at));
+
+        assert!(body.frame().await.unwrap().is_ok());
+        assert!(body.frame().await.is_none());
+
+        tokio::time::advance(Duration::from_secs(2)).await;
+        assert!(body.frame().await.is_none());
+        assert!(body.is_end_stream());
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_core::ready;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use tokio::time::{sleep, Sleep};
+
+pin_project! {
+    /// Body returned by the [`delay`] combinator.
+    ///
+    /// [`delay`]: crate::BodyExt::delay
+    #[derive(Debug)]
+    pub struct Delay<B> {
+        #[pin]
+        inner: B,
+        #[pin]
+        sleep: Option<Sleep>,
+        duration: Duration,
+        elapsed: bool,
+    }
+}
+
+impl<B> Delay<B> {
+    #[inline]
+    pub(crate) fn new(body: B, duration: Duration) -> Self {
+        Self {
+            inner: body,
+            sleep: None,
+            duration,
+            elapsed: false,
+        }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for Delay<B>
+where
+    B: Body,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        if !*this.elapsed {
+            if this.sleep.is_none() {
+                this.sleep.set(Some(sleep(*this.duration)));
+            }
+            ready!(this.sleep.as_mut().as_pin_mut().unwrap().poll(cx));
+            this.sleep.set(None);
+            *this.elapsed = true;
+        }
+
+        this.inner.poll_frame(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.elapsed && self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use tokio::time::Instant;
+
+    use crate::{BodyExt, Full};
+
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn waits_before_first_frame() {
+        let start = Instant::now();
+        let mut body = Box::pin(
+            Full::<_, Infallible>::new(Bytes::from("hello")).delay(Duration::from_secs(2)),
+        );
+
+        assert_eq!(body.size_hint().exact(), Some(5));
+        assert!(!body.is_end_stream());
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hello"
+        );
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+        assert!(body.frame().await.is_none());
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+    }
+}
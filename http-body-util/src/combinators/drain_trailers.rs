@@ -0,0 +1,77 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use http::HeaderMap;
+use http_body::Body;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future that drives a body to completion and resolves to its trailers.
+    ///
+    /// See [`BodyExt::drain_trailers`] for more details.
+    ///
+    /// [`BodyExt::drain_trailers`]: crate::BodyExt::drain_trailers
+    #[derive(Debug)]
+    pub struct DrainTrailers<T>
+    where
+        T: ?Sized,
+    {
+        pub(crate) trailers: Option<HeaderMap>,
+        #[pin]
+        pub(crate) body: T,
+    }
+}
+
+impl<T: Body + ?Sized> Future for DrainTrailers<T> {
+    type Output = Result<Option<HeaderMap>, T::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut me = self.project();
+
+        loop {
+            let frame = match ready!(me.body.as_mut().poll_frame(cx)) {
+                Some(frame) => frame?,
+                None => return Poll::Ready(Ok(me.trailers.take())),
+            };
+
+            if let Ok(trailers) = frame.into_trailers() {
+                match me.trailers {
+                    Some(current) => current.extend(trailers),
+                    None => *me.trailers = Some(trailers),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use http_body::Frame;
+
+    use super::*;
+    use crate::{BodyExt, Full, StreamBody};
+
+    #[tokio::test]
+    async fn returns_trailers_and_discards_data() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("grpc-status", "0".parse().unwrap());
+
+        let body = Full::<_, Infallible>::with_trailers(Bytes::from("hello"), trailers);
+        let trailers = body.drain_trailers().await.unwrap().unwrap();
+        assert_eq!(trailers["grpc-status"], "0");
+    }
+
+    #[tokio::test]
+    async fn no_trailers() {
+        let frames = ["a", "b"].map(|s| Ok::<_, Infallible>(Frame::data(Bytes::from(s))));
+        let body = StreamBody::new(futures_util::stream::iter(frames));
+        assert!(body.drain_trailers().await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,103 @@
+use futures_core::Stream;
+use http_body::{Body, Frame};
+use pin_project_lite::pin_project;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Stream returned by the [`enumerate_frames`] combinator.
+    ///
+    /// [`enumerate_frames`]: crate::BodyExt::enumerate_frames
+    #[derive(Clone, Copy, Debug)]
+    pub struct EnumerateFrames<B> {
+        #[pin]
+        inner: B,
+        count: u64,
+    }
+}
+
+impl<B> EnumerateFrames<B> {
+    #[inline]
+    pub(crate) fn new(body: B) -> Self {
+        Self {
+            inner: body,
+            count: 0,
+        }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Stream for EnumerateFrames<B>
+where
+    B: Body,
+{
+    type Item = Result<(u64, Frame<B::Data>), B::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match this.inner.poll_frame(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Ready(Some(Ok(frame))) => {
+                let index = *this.count;
+                *this.count += 1;
+                Poll::Ready(Some(Ok((index, frame))))
+            }
+            Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(err))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use futures_util::StreamExt;
+    use http::HeaderMap;
+
+    use crate::{BodyExt, StreamBody};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn counts_every_frame() {
+        let frames = [
+            Frame::data(Bytes::from("hello")),
+            Frame::data(Bytes::from("world")),
+            Frame::trailers(HeaderMap::new()),
+        ];
+        let body = StreamBody::new(futures_util::stream::iter(frames.map(Ok::<_, Infallible>)));
+
+        let indexed = body
+            .enumerate_frames()
+            .map(|res| {
+                let (index, frame) = res.unwrap();
+                (index, frame.is_trailers())
+            })
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(indexed, [(0, false), (1, false), (2, true)]);
+    }
+}
@@ -0,0 +1,131 @@
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    any::type_name,
+    fmt,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`err_into`] combinator.
+    ///
+    /// [`err_into`]: crate::BodyExt::err_into
+    pub struct ErrInto<B, E> {
+        #[pin]
+        inner: B,
+        _marker: PhantomData<fn() -> E>,
+    }
+}
+
+impl<B, E> ErrInto<B, E> {
+    #[inline]
+    pub(crate) fn new(body: B) -> Self {
+        Self {
+            inner: body,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B, E> Body for ErrInto<B, E>
+where
+    B: Body,
+    B::Error: Into<E>,
+{
+    type Data = B::Data;
+    type Error = E;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        match this.inner.poll_frame(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Ready(Some(Ok(frame))) => Poll::Ready(Some(Ok(frame))),
+            Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(err.into()))),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl<B: Clone, E> Clone for ErrInto<B, E> {
+    fn clone(&self) -> Self {
+        Self::new(self.inner.clone())
+    }
+}
+
+impl<B: Copy, E> Copy for ErrInto<B, E> {}
+
+impl<B, E> fmt::Debug for ErrInto<B, E>
+where
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ErrInto")
+            .field("inner", &self.inner)
+            .field("error", &type_name::<E>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use bytes::Bytes;
+    use http_body::Frame;
+
+    use crate::{BodyExt, StreamBody};
+
+    #[derive(Debug, PartialEq)]
+    struct MyError(String);
+
+    impl From<io::Error> for MyError {
+        fn from(err: io::Error) -> Self {
+            MyError(err.to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn converts_error() {
+        let frames = [Err::<Frame<Bytes>, _>(io::Error::new(
+            io::ErrorKind::Other,
+            "oops",
+        ))];
+        let body = StreamBody::new(futures_util::stream::iter(frames)).err_into::<MyError>();
+
+        let err = body.collect().await.unwrap_err();
+        assert_eq!(err, MyError("oops".to_owned()));
+    }
+}
@@ -0,0 +1,172 @@
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    error::Error,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`limit_frames`] combinator.
+    ///
+    /// This body will return an error if more than the configured number
+    /// of frames are returned on polling the wrapped body.
+    ///
+    /// [`limit_frames`]: crate::BodyExt::limit_frames
+    #[derive(Clone, Copy, Debug)]
+    pub struct FrameLimited<B> {
+        max_frames: usize,
+        frames: usize,
+        #[pin]
+        inner: B,
+    }
+}
+
+impl<B> FrameLimited<B> {
+    #[inline]
+    pub(crate) fn new(inner: B, max_frames: usize) -> Self {
+        Self {
+            max_frames,
+            frames: 0,
+            inner,
+        }
+    }
+
+    /// Returns the number of frames yielded so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for FrameLimited<B>
+where
+    B: Body,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    type Data = B::Data;
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        let res = match this.inner.poll_frame(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(None) => None,
+            Poll::Ready(Some(Ok(frame))) => {
+                if *this.frames >= *this.max_frames {
+                    Some(Err(FrameLimitError {
+                        limit: *this.max_frames,
+                    }
+                    .into()))
+                } else {
+                    *this.frames += 1;
+                    Some(Ok(frame))
+                }
+            }
+            Poll::Ready(Some(Err(err))) => Some(Err(err.into())),
+        };
+
+        Poll::Ready(res)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// An error returned when a body yields more frames than the configured limit.
+#[derive(Debug)]
+pub struct FrameLimitError {
+    limit: usize,
+}
+
+impl FrameLimitError {
+    /// Returns the maximum number of frames that was exceeded.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl fmt::Display for FrameLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("frame limit exceeded")
+    }
+}
+
+impl Error for FrameLimitError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BodyExt, StreamBody};
+    use bytes::Bytes;
+    use std::convert::Infallible;
+
+    fn body_from_frames(
+        frames: Vec<Frame<Bytes>>,
+    ) -> impl Body<Data = Bytes, Error = Infallible> + Unpin {
+        StreamBody::new(futures_util::stream::iter(
+            frames.into_iter().map(Ok::<_, Infallible>),
+        ))
+    }
+
+    #[tokio::test]
+    async fn under_limit_is_okay() {
+        let inner = body_from_frames(vec![
+            Frame::data(Bytes::from("a")),
+            Frame::trailers(http::HeaderMap::new()),
+        ]);
+        let body = &mut inner.limit_frames(2);
+
+        assert!(body.frame().await.unwrap().unwrap().is_data());
+        assert!(body.frame().await.unwrap().unwrap().is_trailers());
+        assert_eq!(body.frames(), 2);
+        assert!(body.frame().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn over_limit_returns_error() {
+        let inner = body_from_frames(vec![
+            Frame::data(Bytes::from("a")),
+            Frame::data(Bytes::from("b")),
+            Frame::data(Bytes::from("c")),
+        ]);
+        let body = &mut inner.limit_frames(2);
+
+        body.frame().await.unwrap().unwrap();
+        body.frame().await.unwrap().unwrap();
+        let error = body.frame().await.unwrap().unwrap_err();
+        assert!(matches!(
+            error.downcast_ref(),
+            Some(FrameLimitError { limit: 2 })
+        ));
+        assert_eq!(body.frames(), 2);
+    }
+}
@@ -0,0 +1,149 @@
+use bytes::{Buf, Bytes};
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`intersperse`] combinator.
+    ///
+    /// [`intersperse`]: crate::BodyExt::intersperse
+    #[derive(Clone, Debug)]
+    pub struct Intersperse<B> {
+        #[pin]
+        inner: B,
+        sep: Bytes,
+        pending: Option<Bytes>,
+        seen_data: bool,
+    }
+}
+
+impl<B> Intersperse<B> {
+    #[inline]
+    pub(crate) fn new(body: B, sep: Bytes) -> Self {
+        Self {
+            inner: body,
+            sep,
+            pending: None,
+            seen_data: false,
+        }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+}
+
+impl<B> Body for Intersperse<B>
+where
+    B: Body,
+{
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        if let Some(data) = this.pending.take() {
+            return Poll::Ready(Some(Ok(Frame::data(data))));
+        }
+
+        loop {
+            let frame = match this.inner.as_mut().poll_frame(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(Ok(frame))) => frame,
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+            };
+
+            let frame = match frame.into_data() {
+                Ok(mut data) => {
+                    if !data.has_remaining() {
+                        continue;
+                    }
+                    let data = data.copy_to_bytes(data.remaining());
+                    if !*this.seen_data {
+                        *this.seen_data = true;
+                        return Poll::Ready(Some(Ok(Frame::data(data))));
+                    }
+                    *this.pending = Some(data);
+                    return Poll::Ready(Some(Ok(Frame::data(this.sep.clone()))));
+                }
+                Err(frame) => frame,
+            };
+
+            return Poll::Ready(Some(Ok(
+                frame.map_data(|mut data| data.copy_to_bytes(data.remaining()))
+            )));
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.pending.is_none() && self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        let pending = self.pending.as_ref().map_or(0, |data| data.len() as u64);
+        let inner = self.inner.size_hint();
+        if self.sep.is_empty() || inner.upper() == Some(0) {
+            return SizeHint::with_exact(pending) + inner;
+        }
+
+        // The number of separators depends on how many frames the inner body yields.
+        let mut hint = SizeHint::new();
+        hint.set_lower(pending + inner.lower());
+        hint
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use http::HeaderMap;
+
+    use super::*;
+    use crate::{BodyExt, Empty, Full, StreamBody};
+
+    #[tokio::test]
+    async fn separators_between_data_frames() {
+        let frames = [
+            Frame::data(Bytes::from("a")),
+            Frame::data(Bytes::from("b")),
+            Frame::data(Bytes::from("c")),
+            Frame::trailers(HeaderMap::new()),
+        ];
+        let body = StreamBody::new(futures_util::stream::iter(frames.map(Ok::<_, Infallible>)))
+            .intersperse(Bytes::from(", "));
+
+        let collected = body.collect().await.unwrap();
+        assert!(collected.trailers().is_some());
+        assert_eq!(collected.to_bytes(), "a, b, c");
+    }
+
+    #[tokio::test]
+    async fn single_and_empty_bodies() {
+        let single = Full::<_, Infallible>::new(Bytes::from("a")).intersperse(Bytes::from(", "));
+        assert_eq!(single.collect().await.unwrap().to_bytes(), "a");
+
+        let empty = Empty::<Bytes, Infallible>::new().intersperse(Bytes::from(", "));
+        assert_eq!(empty.size_hint().exact(), Some(0));
+        assert!(empty.collect().await.unwrap().to_bytes().is_empty());
+    }
+}
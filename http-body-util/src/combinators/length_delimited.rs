@@ -0,0 +1,297 @@
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use http_body::{Body, Frame};
+use pin_project_lite::pin_project;
+use std::{
+    error::Error,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+const PREFIX_LEN: usize = 4;
+const DEFAULT_MAX_FRAME_LENGTH: usize = 8 * 1024 * 1024;
+
+pin_project! {
+    /// Body returned by the [`length_delimited`] combinator.
+    ///
+    /// [`length_delimited`]: crate::BodyExt::length_delimited
+    #[derive(Clone, Debug)]
+    pub struct LengthDelimited<B> {
+        #[pin]
+        inner: B,
+        buf: BytesMut,
+        max_frame_length: usize,
+        done: bool,
+    }
+}
+
+impl<B> LengthDelimited<B> {
+    #[inline]
+    pub(crate) fn new(body: B) -> Self {
+        Self {
+            inner: body,
+            buf: BytesMut::new(),
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+            done: false,
+        }
+    }
+
+    /// Set the maximum length of a record's payload.
+    ///
+    /// A record whose length prefix exceeds this is rejected with a [`RecordTooLongError`]
+    /// before any of its payload is buffered. Defaults to 8 MiB.
+    pub fn max_frame_length(mut self, max: usize) -> Self {
+        self.max_frame_length = max;
+        self
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+}
+
+fn next_record(buf: &mut BytesMut, max: usize) -> Result<Option<Bytes>, RecordTooLongError> {
+    if buf.len() < PREFIX_LEN {
+        return Ok(None);
+    }
+    let len = u32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]) as usize;
+    if len > max {
+        return Err(RecordTooLongError { len, max });
+    }
+    if buf.len() - PREFIX_LEN < len {
+        return Ok(None);
+    }
+    buf.advance(PREFIX_LEN);
+    Ok(Some(buf.split_to(len).freeze()))
+}
+
+impl<B> Body for LengthDelimited<B>
+where
+    B: Body,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    type Data = Bytes;
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        loop {
+            match next_record(this.buf, *this.max_frame_length) {
+                Ok(Some(record)) => return Poll::Ready(Some(Ok(Frame::data(record)))),
+                Ok(None) => {}
+                Err(err) => {
+                    *this.done = true;
+                    this.buf.clear();
+                    return Poll::Ready(Some(Err(err.into())));
+                }
+            }
+
+            if *this.done {
+                return Poll::Ready(None);
+            }
+
+            let frame = match this.inner.as_mut().poll_frame(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Some(Ok(frame))) => Some(frame),
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err.into()))),
+                Poll::Ready(None) => None,
+            };
+
+            let frame = match frame.map(Frame::into_data) {
+                Some(Ok(data)) => {
+                    this.buf.put(data);
+                    continue;
+                }
+                Some(Err(frame)) => Some(frame),
+                None => None,
+            };
+
+            if !this.buf.is_empty() {
+                *this.done = true;
+                let err = IncompleteRecordError {
+                    buffered: this.buf.len(),
+                };
+                this.buf.clear();
+                return Poll::Ready(Some(Err(err.into())));
+            }
+
+            match frame {
+                Some(frame) => {
+                    let frame = frame.map_data(|mut data| data.copy_to_bytes(data.remaining()));
+                    return Poll::Ready(Some(Ok(frame)));
+                }
+                None => {
+                    *this.done = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.done && self.buf.is_empty()
+    }
+}
+
+/// An error returned when a body ends in the middle of a length-delimited record.
+#[derive(Debug)]
+pub struct IncompleteRecordError {
+    buffered: usize,
+}
+
+impl IncompleteRecordError {
+    /// Returns the number of bytes of the incomplete record, including its length prefix.
+    pub fn buffered(&self) -> usize {
+        self.buffered
+    }
+}
+
+impl fmt::Display for IncompleteRecordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("body ended with an incomplete record")
+    }
+}
+
+impl Error for IncompleteRecordError {}
+
+/// An error returned when a length-delimited record exceeds the configured maximum length.
+#[derive(Debug)]
+pub struct RecordTooLongError {
+    len: usize,
+    max: usize,
+}
+
+impl RecordTooLongError {
+    /// Returns the payload length given by the record's prefix.
+    pub fn record_len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the configured maximum payload length.
+    pub fn max(&self) -> usize {
+        self.max
+    }
+}
+
+impl fmt::Display for RecordTooLongError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "record of {} bytes exceeds the maximum of {} bytes",
+            self.len, self.max
+        )
+    }
+}
+
+impl Error for RecordTooLongError {}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use http::HeaderMap;
+
+    use super::*;
+    use crate::{BodyExt, StreamBody};
+
+    fn body_from_frames(
+        frames: Vec<Frame<Bytes>>,
+    ) -> impl Body<Data = Bytes, Error = Infallible> + Unpin {
+        StreamBody::new(futures_util::stream::iter(
+            frames.into_iter().map(Ok::<_, Infallible>),
+        ))
+    }
+
+    #[tokio::test]
+    async fn reframes_records_across_frames() {
+        let body = &mut body_from_frames(vec![
+            Frame::data(Bytes::from_static(b"\0\0\0\x05hel")),
+            Frame::data(Bytes::from_static(b"lo\0\0\0\0\0\0\0\x02hi")),
+            Frame::trailers(HeaderMap::new()),
+        ])
+        .length_delimited();
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hello"
+        );
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            ""
+        );
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hi"
+        );
+        assert!(body.frame().await.unwrap().unwrap().is_trailers());
+        assert!(body.frame().await.is_none());
+        assert!(body.is_end_stream());
+    }
+
+    #[tokio::test]
+    async fn partial_record_is_an_error() {
+        let body = &mut body_from_frames(vec![Frame::data(Bytes::from_static(b"\0\0\0\x05hel"))])
+            .length_delimited();
+
+        let err = body.frame().await.unwrap().unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<IncompleteRecordError>()
+                .unwrap()
+                .buffered(),
+            7
+        );
+        assert!(body.frame().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn oversized_prefix_is_rejected_before_buffering() {
+        let body = &mut body_from_frames(vec![
+            Frame::data(Bytes::from_static(b"\xff\xff\xff\xff")),
+            Frame::data(Bytes::from_static(b"never read")),
+        ])
+        .length_delimited();
+
+        let err = body.frame().await.unwrap().unwrap_err();
+        let err = err.downcast_ref::<RecordTooLongError>().unwrap();
+        assert_eq!(err.record_len(), 0xFFFF_FFFF);
+        assert_eq!(err.max(), 8 * 1024 * 1024);
+        assert!(body.frame().await.is_none());
+        assert!(body.is_end_stream());
+    }
+
+    #[tokio::test]
+    async fn max_frame_length_is_configurable() {
+        let body = &mut body_from_frames(vec![Frame::data(Bytes::from_static(
+            b"\0\0\0\x02hi\0\0\0\x03hey",
+        ))])
+        .length_delimited()
+        .max_frame_length(2);
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hi"
+        );
+        let err = body.frame().await.unwrap().unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<RecordTooLongError>()
+                .unwrap()
+                .record_len(),
+            3
+        );
+    }
+}
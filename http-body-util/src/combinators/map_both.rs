@@ -0,0 +1,117 @@
+use bytes::Buf;
+use http_body::{Body, Frame};
+use pin_project_lite::pin_project;
+use std::{
+    any::type_name,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`map_both`] combinator.
+    ///
+    /// [`map_both`]: crate::BodyExt::map_both
+    #[derive(Clone, Copy)]
+    pub struct MapBoth<B, F, G> {
+        #[pin]
+        inner: B,
+        f_frame: F,
+        f_err: G,
+    }
+}
+
+impl<B, F, G> MapBoth<B, F, G> {
+    #[inline]
+    pub(crate) fn new(body: B, f_frame: F, f_err: G) -> Self {
+        Self {
+            inner: body,
+            f_frame,
+            f_err,
+        }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B, F, G, B2, E> Body for MapBoth<B, F, G>
+where
+    B: Body,
+    F: FnMut(Frame<B::Data>) -> Frame<B2>,
+    G: FnMut(B::Error) -> E,
+    B2: Buf,
+{
+    type Data = B2;
+    type Error = E;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        match this.inner.poll_frame(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Ready(Some(Ok(frame))) => Poll::Ready(Some(Ok((this.f_frame)(frame)))),
+            Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err((this.f_err)(err)))),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+}
+
+impl<B, F, G> fmt::Debug for MapBoth<B, F, G>
+where
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapBoth")
+            .field("inner", &self.inner)
+            .field("f_frame", &type_name::<F>())
+            .field("f_err", &type_name::<G>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::{BodyExt, StreamBody};
+
+    #[tokio::test]
+    async fn maps_frames_and_errors() {
+        let frames = [Ok(Frame::data(Bytes::from("hello"))), Err("boom")];
+        let mut body = StreamBody::new(futures_util::stream::iter(frames)).map_both(
+            |frame| frame.map_data(|data| Bytes::from(data.to_ascii_uppercase())),
+            |err| err.len(),
+        );
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "HELLO"
+        );
+        assert_eq!(body.frame().await.unwrap().unwrap_err(), 4);
+    }
+}
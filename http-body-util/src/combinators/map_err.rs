@@ -87,3 +87,25 @@ where
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use bytes::Bytes;
+
+    use crate::{BodyExt, StreamBody};
+
+    #[tokio::test]
+    async fn map_err_boxed() {
+        let frames = vec![Err::<http_body::Frame<Bytes>, _>(io::Error::new(
+            io::ErrorKind::Other,
+            "oh no",
+        ))];
+        let body = StreamBody::new(futures_util::stream::iter(frames)).map_err_boxed();
+
+        let err = body.collect().await.unwrap_err();
+        assert!(err.is::<io::Error>());
+        assert_eq!(err.to_string(), "oh no");
+    }
+}
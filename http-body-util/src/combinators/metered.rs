@@ -0,0 +1,171 @@
+use bytes::Buf;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+pin_project! {
+    /// Body returned by the [`meter`] combinator.
+    ///
+    /// [`meter`]: crate::BodyExt::meter
+    #[derive(Debug)]
+    pub struct MeteredBody<B> {
+        #[pin]
+        inner: B,
+        meter: Meter,
+    }
+}
+
+impl<B> MeteredBody<B> {
+    #[inline]
+    pub(crate) fn new(body: B) -> (Self, Meter) {
+        let meter = Meter {
+            shared: Arc::new(Shared {
+                start: Instant::now(),
+                bytes: AtomicU64::new(0),
+                last_nanos: AtomicU64::new(0),
+            }),
+        };
+        let body = Self {
+            inner: body,
+            meter: meter.clone(),
+        };
+        (body, meter)
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for MeteredBody<B>
+where
+    B: Body,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        let res = this.inner.poll_frame(cx);
+        if let Poll::Ready(Some(Ok(frame))) = &res {
+            if let Some(data) = frame.data_ref() {
+                this.meter.record(data.remaining() as u64);
+            }
+        }
+        res
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// A handle to the throughput measurements of a [`MeteredBody`].
+///
+/// Cloning a `Meter` returns another handle to the same measurements. They remain readable
+/// after the body is dropped.
+#[derive(Clone, Debug)]
+pub struct Meter {
+    shared: Arc<Shared>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    start: Instant,
+    bytes: AtomicU64,
+    last_nanos: AtomicU64,
+}
+
+impl Meter {
+    fn record(&self, bytes: u64) {
+        let nanos = self.shared.start.elapsed().as_nanos();
+        self.shared.bytes.fetch_add(bytes, Ordering::Relaxed);
+        self.shared
+            .last_nanos
+            .fetch_max(nanos.min(u64::MAX as u128) as u64, Ordering::Relaxed);
+    }
+
+    /// Returns the number of data bytes yielded so far.
+    pub fn bytes_total(&self) -> u64 {
+        self.shared.bytes.load(Ordering::Relaxed)
+    }
+
+    /// Returns the time between the creation of the body and its most recent DATA frame.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.shared.last_nanos.load(Ordering::Relaxed))
+    }
+
+    /// Returns the average throughput so far, in bytes per second.
+    ///
+    /// This is [`Meter::bytes_total`] divided by [`Meter::elapsed`], or zero if no time has
+    /// elapsed.
+    pub fn bytes_per_sec(&self) -> f64 {
+        let elapsed = self.elapsed().as_secs_f64();
+        if elapsed > 0.0 {
+            self.bytes_total() as f64 / elapsed
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+
+    use crate::{BodyExt, StreamBody};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn measures_data_frames() {
+        let frames = [Bytes::from("hello"), Bytes::from(" world")];
+        let (body, meter) = StreamBody::new(futures_util::stream::iter(
+            frames.map(Frame::data).map(Ok::<_, Infallible>),
+        ))
+        .meter();
+        assert_eq!(meter.bytes_total(), 0);
+        assert_eq!(meter.elapsed(), Duration::ZERO);
+        assert_eq!(meter.bytes_per_sec(), 0.0);
+
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), "hello world");
+        assert_eq!(meter.bytes_total(), 11);
+        if meter.elapsed() > Duration::ZERO {
+            assert!(meter.bytes_per_sec() > 0.0);
+        }
+    }
+}
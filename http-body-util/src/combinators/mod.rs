@@ -1,17 +1,124 @@
 //! Combinators for the `Body` trait.
+//!
+//! Most combinators implement [`Clone`] when the body and any closures or futures they wrap do,
+//! so a body template can be cloned and sent to several tasks. The exceptions are:
+//!
+//! - combinators that hold a timer: `CoalesceFor`, `Deadline`, `Delay`, `Throttle` and
+//!   `Timeout`;
+//! - combinators that may hold a frame, which is not `Clone`: [`Buffered`] and [`Peekable`];
+//! - combinators that share state with a handle returned alongside them: [`CountingBody`],
+//!   [`DataFrames`], [`MeteredBody`], [`OnEnd`] and [`RecordingBody`];
+//! - [`BoxBody`], [`UnsyncBoxBody`] and [`LocalBoxBody`]. Use [`CloneBoxBody`] for a boxed
+//!   body that can be cloned.
 
+mod assert_size_hint;
 mod box_body;
+mod buffered;
+mod catch_unwind;
+mod chain;
+mod coalesce;
 mod collect;
+mod concat;
+mod context;
+mod count_bytes;
+mod data_frames;
+mod drain_trailers;
+mod enumerate_frames;
+mod err_into;
+mod filter_frame;
 mod frame;
+mod frame_limited;
+mod fuse;
+mod inspect_frame;
+mod intersperse;
+mod length_delimited;
+mod map_both;
+mod map_data;
 mod map_err;
 mod map_frame;
+mod metered;
+mod on_end;
+mod pace;
+mod peekable;
+mod prepend;
+mod progress;
+mod record;
+mod replayable;
+mod route;
+mod scan_frames;
+mod skip;
+mod strip_trailers;
+mod take;
+mod tee;
+mod then_frame;
+mod verify_length;
 mod with_trailers;
+mod with_trailers_fn;
 
 pub use self::{
-    box_body::{BoxBody, UnsyncBoxBody},
-    collect::Collect,
+    assert_size_hint::AssertSizeHint,
+    box_body::{BoxBody, CloneBoxBody, LocalBoxBody, UnsyncBoxBody},
+    buffered::Buffered,
+    catch_unwind::{BodyPanicked, CatchUnwind},
+    chain::Chain,
+    coalesce::Coalesce,
+    collect::{
+        Collect, CollectInto, CollectLimitError, CollectLimited, CollectOpts, TrailersLimitError,
+    },
+    concat::{Concat, ConcatAll, ConcatStream},
+    context::{ContextError, WithContext},
+    count_bytes::CountingBody,
+    data_frames::{DataFrames, Trailers},
+    drain_trailers::DrainTrailers,
+    enumerate_frames::EnumerateFrames,
+    err_into::ErrInto,
+    filter_frame::FilterFrame,
     frame::Frame,
+    frame_limited::{FrameLimitError, FrameLimited},
+    fuse::Fuse,
+    inspect_frame::InspectFrame,
+    intersperse::Intersperse,
+    length_delimited::{IncompleteRecordError, LengthDelimited, RecordTooLongError},
+    map_both::MapBoth,
+    map_data::MapData,
     map_err::MapErr,
     map_frame::MapFrame,
-    with_trailers::WithTrailers,
+    metered::{Meter, MeteredBody},
+    on_end::{EndReason, OnEnd},
+    pace::Pace,
+    peekable::{Peek, Peekable},
+    prepend::Prepend,
+    progress::{OnProgress, Progress},
+    record::{RecordError, Recording, RecordingBody},
+    replayable::{NotReplayableError, Replayable},
+    route::Route,
+    scan_frames::ScanFrames,
+    skip::Skip,
+    strip_trailers::StripTrailers,
+    take::Take,
+    tee::Tee,
+    then_frame::ThenFrame,
+    verify_length::{LengthMismatch, VerifyLength},
+    with_trailers::{TrailerMerge, WithTrailers},
+    with_trailers_fn::WithTrailersFn,
+};
+
+#[cfg(feature = "time")]
+mod coalesce_for;
+#[cfg(feature = "time")]
+mod deadline;
+#[cfg(feature = "time")]
+mod delay;
+#[cfg(feature = "time")]
+mod throttle;
+#[cfg(feature = "time")]
+mod timeout;
+
+#[cfg(feature = "time")]
+pub use self::{
+    coalesce_for::CoalesceFor,
+    deadline::{Deadline, DeadlineExceeded},
+    delay::Delay,
+    throttle::Throttle,
+    timeout::{BodyTimeoutError, Timeout},
 };
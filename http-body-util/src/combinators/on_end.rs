@@ -0,0 +1,175 @@
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    any::type_name,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`on_end`] combinator.
+    ///
+    /// [`on_end`]: crate::BodyExt::on_end
+    pub struct OnEnd<B, F>
+    where
+        B: Body,
+        F: FnOnce(EndReason<'_, B::Error>),
+    {
+        #[pin]
+        inner: B,
+        f: Option<F>,
+    }
+
+    impl<B, F> PinnedDrop for OnEnd<B, F>
+    where
+        B: Body,
+        F: FnOnce(EndReason<'_, B::Error>),
+    {
+        fn drop(this: Pin<&mut Self>) {
+            if let Some(f) = this.project().f.take() {
+                f(EndReason::Dropped);
+            }
+        }
+    }
+}
+
+/// The reason a body passed to [`on_end`] finished.
+///
+/// [`on_end`]: crate::BodyExt::on_end
+#[derive(Debug)]
+pub enum EndReason<'a, E> {
+    /// The body yielded all of its frames.
+    Complete,
+    /// The body yielded an error.
+    Error(&'a E),
+    /// The body was dropped before it finished.
+    Dropped,
+}
+
+impl<B, F> OnEnd<B, F>
+where
+    B: Body,
+    F: FnOnce(EndReason<'_, B::Error>),
+{
+    #[inline]
+    pub(crate) fn new(body: B, f: F) -> Self {
+        Self {
+            inner: body,
+            f: Some(f),
+        }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+}
+
+impl<B, F> Body for OnEnd<B, F>
+where
+    B: Body,
+    F: FnOnce(EndReason<'_, B::Error>),
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        match this.inner.poll_frame(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => {
+                if let Some(f) = this.f.take() {
+                    f(EndReason::Complete);
+                }
+                Poll::Ready(None)
+            }
+            Poll::Ready(Some(Ok(frame))) => Poll::Ready(Some(Ok(frame))),
+            Poll::Ready(Some(Err(err))) => {
+                if let Some(f) = this.f.take() {
+                    f(EndReason::Error(&err));
+                }
+                Poll::Ready(Some(Err(err)))
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl<B, F> fmt::Debug for OnEnd<B, F>
+where
+    B: Body + fmt::Debug,
+    F: FnOnce(EndReason<'_, B::Error>),
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OnEnd")
+            .field("inner", &self.inner)
+            .field("f", &type_name::<F>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, convert::Infallible};
+
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::{BodyExt, Full, StreamBody};
+
+    fn reason<E: fmt::Debug>(reason: EndReason<'_, E>) -> String {
+        format!("{:?}", reason)
+    }
+
+    #[tokio::test]
+    async fn runs_once_on_completion() {
+        let ended = RefCell::new(Vec::new());
+        let body = Full::<_, Infallible>::new(Bytes::from("hello"))
+            .on_end(|r| ended.borrow_mut().push(reason(r)));
+
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), "hello");
+        assert_eq!(*ended.borrow(), ["Complete"]);
+    }
+
+    #[tokio::test]
+    async fn runs_on_error() {
+        let ended = RefCell::new(Vec::new());
+        let body = StreamBody::new(futures_util::stream::iter([Err::<Frame<Bytes>, _>("boom")]))
+            .on_end(|r| ended.borrow_mut().push(reason(r)));
+
+        assert!(body.collect().await.is_err());
+        assert_eq!(*ended.borrow(), ["Error(\"boom\")"]);
+    }
+
+    #[test]
+    fn runs_on_drop() {
+        let ended = RefCell::new(Vec::new());
+        let body = Full::<_, Infallible>::new(Bytes::from("hello"))
+            .on_end(|r| ended.borrow_mut().push(reason(r)));
+
+        drop(body);
+        assert_eq!(*ended.borrow(), ["Dropped"]);
+    }
+}
@@ -0,0 +1,110 @@
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`yield_now_between_frames`] combinator.
+    ///
+    /// [`yield_now_between_frames`]: crate::BodyExt::yield_now_between_frames
+    #[derive(Clone, Copy, Debug)]
+    pub struct Pace<B> {
+        #[pin]
+        inner: B,
+        every: usize,
+        since_yield: usize,
+    }
+}
+
+impl<B> Pace<B> {
+    #[inline]
+    pub(crate) fn new(body: B, every: usize) -> Self {
+        assert!(every > 0, "`every` must be greater than zero");
+        Self {
+            inner: body,
+            every,
+            since_yield: 0,
+        }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for Pace<B>
+where
+    B: Body,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+
+        if *this.since_yield >= *this.every {
+            *this.since_yield = 0;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        let frame = this.inner.poll_frame(cx);
+        if let Poll::Ready(Some(_)) = frame {
+            *this.since_yield += 1;
+        }
+        frame
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::{BodyExt, Repeat};
+
+    #[test]
+    fn yields_every_n_frames() {
+        let body = Repeat::<_>::new(Bytes::from("a"), 5).yield_now_between_frames(2);
+        futures_util::pin_mut!(body);
+        let waker = futures_util::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let polls = std::iter::from_fn(|| match body.as_mut().poll_frame(&mut cx) {
+            Poll::Ready(None) => None,
+            Poll::Ready(Some(_)) => Some('r'),
+            Poll::Pending => Some('p'),
+        })
+        .collect::<String>();
+        assert_eq!(polls, "rrprrpr");
+    }
+}
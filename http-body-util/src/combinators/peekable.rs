@@ -0,0 +1,171 @@
+use bytes::Buf;
+use futures_core::ready;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`peekable`] combinator.
+    ///
+    /// [`peekable`]: crate::BodyExt::peekable
+    pub struct Peekable<B: Body> {
+        #[pin]
+        inner: B,
+        peeked: Option<Option<Result<Frame<B::Data>, B::Error>>>,
+    }
+}
+
+impl<B: Body> Peekable<B> {
+    #[inline]
+    pub(crate) fn new(body: B) -> Self {
+        Self {
+            inner: body,
+            peeked: None,
+        }
+    }
+
+    /// Returns a future that resolves to a reference to the next frame, without consuming it.
+    ///
+    /// The peeked frame is yielded by the next call to `poll_frame`. Peeking repeatedly
+    /// without polling the body returns the same frame.
+    pub fn peek(&mut self) -> Peek<'_, B>
+    where
+        B: Unpin,
+    {
+        Peek(Some(self))
+    }
+
+    fn poll_fill(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.project();
+        if this.peeked.is_none() {
+            *this.peeked = Some(ready!(this.inner.poll_frame(cx)));
+        }
+        Poll::Ready(())
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+}
+
+impl<B: Body> Body for Peekable<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        match this.peeked.take() {
+            Some(peeked) => Poll::Ready(peeked),
+            None => this.inner.poll_frame(cx),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        match &self.peeked {
+            Some(None) => true,
+            Some(Some(_)) => false,
+            None => self.inner.is_end_stream(),
+        }
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        let peeked = match &self.peeked {
+            Some(Some(Ok(frame))) => frame.data_ref().map_or(0, |data| data.remaining() as u64),
+            _ => 0,
+        };
+        SizeHint::with_exact(peeked) + self.inner.size_hint()
+    }
+}
+
+impl<B> fmt::Debug for Peekable<B>
+where
+    B: Body + fmt::Debug,
+    B::Data: fmt::Debug,
+    B::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Peekable")
+            .field("inner", &self.inner)
+            .field("peeked", &self.peeked)
+            .finish()
+    }
+}
+
+#[must_use = "futures don't do anything unless polled"]
+/// Future that resolves to a reference to the next frame of a [`Peekable`] body.
+pub struct Peek<'a, B: Body>(Option<&'a mut Peekable<B>>);
+
+impl<'a, B: Body + Unpin> Future for Peek<'a, B> {
+    type Output = Option<&'a Result<Frame<B::Data>, B::Error>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let body = self.0.as_mut().expect("polled after completion");
+        ready!(Pin::new(&mut **body).poll_fill(cx));
+
+        let body: &'a Peekable<B> = self.0.take().unwrap();
+        Poll::Ready(body.peeked.as_ref().unwrap().as_ref())
+    }
+}
+
+impl<B> fmt::Debug for Peek<'_, B>
+where
+    B: Body + fmt::Debug,
+    B::Data: fmt::Debug,
+    B::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Peek").field(&self.0).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::{BodyExt, StreamBody};
+
+    #[tokio::test]
+    async fn peeked_frame_is_not_lost() {
+        let frames = ["hello", " world"].map(|s| Ok::<_, Infallible>(Frame::data(Bytes::from(s))));
+        let mut body = StreamBody::new(futures_util::stream::iter(frames)).peekable();
+
+        let peeked = body.peek().await.unwrap().as_ref().unwrap();
+        assert_eq!(peeked.data_ref().unwrap(), "hello");
+        let peeked = body.peek().await.unwrap().as_ref().unwrap();
+        assert_eq!(peeked.data_ref().unwrap(), "hello");
+
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), "hello world");
+    }
+
+    #[tokio::test]
+    async fn peek_end_of_body() {
+        let mut body = crate::Empty::<Bytes>::new().peekable();
+
+        assert!(body.peek().await.is_none());
+        assert!(body.is_end_stream());
+        assert!(body.frame().await.is_none());
+    }
+}
@@ -0,0 +1,106 @@
+use bytes::Buf;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`prepend_data`] combinator.
+    ///
+    /// [`prepend_data`]: crate::BodyExt::prepend_data
+    #[derive(Clone, Copy, Debug)]
+    pub struct Prepend<B, D> {
+        #[pin]
+        inner: B,
+        data: Option<D>,
+    }
+}
+
+impl<B, D: Buf> Prepend<B, D> {
+    #[inline]
+    pub(crate) fn new(body: B, data: D) -> Self {
+        Self {
+            inner: body,
+            data: Some(data).filter(Buf::has_remaining),
+        }
+    }
+}
+
+impl<B, D> Prepend<B, D> {
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B, D> Body for Prepend<B, D>
+where
+    B: Body<Data = D>,
+    D: Buf,
+{
+    type Data = D;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        if let Some(data) = this.data.take() {
+            return Poll::Ready(Some(Ok(Frame::data(data))));
+        }
+        this.inner.poll_frame(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.data.is_none() && self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        match &self.data {
+            Some(data) => SizeHint::with_exact(data.remaining() as u64) + self.inner.size_hint(),
+            None => self.inner.size_hint(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::{BodyExt, Full};
+
+    #[tokio::test]
+    async fn yields_data_first() {
+        let mut body =
+            Full::<_, Infallible>::new(Bytes::from("world")).prepend_data(Bytes::from("hello "));
+        assert_eq!(body.size_hint().exact(), Some(11));
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hello "
+        );
+        assert_eq!(body.size_hint().exact(), Some(5));
+        assert_eq!(body.collect().await.unwrap().to_bytes(), "world");
+    }
+}
@@ -0,0 +1,169 @@
+use bytes::Buf;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    any::type_name,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`progress`] combinator.
+    ///
+    /// [`progress`]: crate::BodyExt::progress
+    pub struct OnProgress<B, F> {
+        #[pin]
+        inner: B,
+        f: F,
+        progress: Progress,
+        finished: bool,
+    }
+}
+
+/// The progress of a body, as reported by the [`progress`] combinator.
+///
+/// [`progress`]: crate::BodyExt::progress
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Progress {
+    /// The number of data bytes yielded so far.
+    pub bytes_so_far: u64,
+    /// The total number of data bytes, if the body's exact size was known up front.
+    pub total: Option<u64>,
+}
+
+impl<B, F> OnProgress<B, F>
+where
+    B: Body,
+{
+    #[inline]
+    pub(crate) fn new(body: B, f: F) -> Self {
+        let total = body.size_hint().exact();
+        Self {
+            inner: body,
+            f,
+            progress: Progress {
+                bytes_so_far: 0,
+                total,
+            },
+            finished: false,
+        }
+    }
+}
+
+impl<B, F> OnProgress<B, F> {
+    /// Returns the progress of the body so far.
+    pub fn current(&self) -> Progress {
+        self.progress
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B, F> Body for OnProgress<B, F>
+where
+    B: Body,
+    F: FnMut(Progress),
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        match this.inner.poll_frame(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => {
+                if !*this.finished {
+                    *this.finished = true;
+                    (this.f)(*this.progress);
+                }
+                Poll::Ready(None)
+            }
+            Poll::Ready(Some(Ok(frame))) => {
+                if let Some(data) = frame.data_ref() {
+                    this.progress.bytes_so_far += data.remaining() as u64;
+                    (this.f)(*this.progress);
+                }
+                Poll::Ready(Some(Ok(frame)))
+            }
+            Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(err))),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl<B, F> fmt::Debug for OnProgress<B, F>
+where
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OnProgress")
+            .field("inner", &self.inner)
+            .field("f", &type_name::<F>())
+            .field("progress", &self.progress)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::{BodyExt, Full, StreamBody};
+
+    #[tokio::test]
+    async fn reports_progress_with_total() {
+        let mut reports = Vec::new();
+        let body = Full::<_, Infallible>::new(Bytes::from("hello"))
+            .chain(Full::<_, Infallible>::new(Bytes::from(" world")))
+            .progress(|progress| reports.push((progress.bytes_so_far, progress.total)));
+
+        body.collect().await.unwrap();
+        assert_eq!(reports, [(5, Some(11)), (11, Some(11)), (11, Some(11))]);
+    }
+
+    #[tokio::test]
+    async fn unknown_total() {
+        let frames = ["hello"].map(|s| Ok::<_, Infallible>(Frame::data(Bytes::from(s))));
+        let mut last = None;
+        let body = StreamBody::new(futures_util::stream::iter(frames))
+            .progress(|progress| last = Some(progress));
+
+        body.collect().await.unwrap();
+        let last = last.unwrap();
+        assert_eq!(last.bytes_so_far, 5);
+        assert_eq!(last.total, None);
+    }
+}
@@ -0,0 +1,217 @@
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    error::Error,
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{
+    util::{oneshot, Receiver, Sender},
+    Collected,
+};
+
+pin_project! {
+    /// Body returned by the [`record`] combinator.
+    ///
+    /// [`record`]: crate::BodyExt::record
+    pub struct RecordingBody<B: Body> {
+        #[pin]
+        inner: B,
+        collected: Option<Collected<B::Data>>,
+        tx: Option<Sender<Result<Collected<B::Data>, RecordError>>>,
+    }
+}
+
+impl<B: Body> RecordingBody<B> {
+    #[inline]
+    pub(crate) fn new(body: B) -> (Self, Recording<B::Data>) {
+        let (tx, rx) = oneshot();
+        let body = Self {
+            inner: body,
+            collected: Some(Collected::default()),
+            tx: Some(tx),
+        };
+        (body, Recording { rx })
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+}
+
+impl<B> Body for RecordingBody<B>
+where
+    B: Body,
+    B::Data: Clone,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        let frame = match this.inner.poll_frame(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(frame) => frame,
+        };
+
+        match &frame {
+            Some(Ok(frame)) => {
+                if let Some(collected) = this.collected {
+                    if let Some(data) = frame.data_ref() {
+                        collected.push_frame(Frame::data(data.clone()));
+                    } else if let Some(trailers) = frame.trailers_ref() {
+                        collected.push_frame(Frame::trailers(trailers.clone()));
+                    }
+                }
+            }
+            Some(Err(_)) => {
+                this.collected.take();
+                if let Some(tx) = this.tx.take() {
+                    tx.send(Some(Err(RecordError { dropped: false })));
+                }
+            }
+            None => {
+                if let (Some(collected), Some(tx)) = (this.collected.take(), this.tx.take()) {
+                    tx.send(Some(Ok(collected)));
+                }
+            }
+        }
+
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl<B> fmt::Debug for RecordingBody<B>
+where
+    B: Body + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RecordingBody")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+/// Future that resolves to everything recorded by a [`RecordingBody`].
+///
+/// The future resolves once the body has reached its end, yielding the recorded data and
+/// trailers as a [`Collected`]. It resolves to a [`RecordError`] if the body yielded an error
+/// or was dropped before reaching its end.
+#[must_use = "futures don't do anything unless polled"]
+pub struct Recording<D> {
+    rx: Receiver<Result<Collected<D>, RecordError>>,
+}
+
+impl<D> Future for Recording<D> {
+    type Output = Result<Collected<D>, RecordError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.rx
+            .poll_recv(cx)
+            .map(|result| result.unwrap_or(Err(RecordError { dropped: true })))
+    }
+}
+
+impl<D> fmt::Debug for Recording<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Recording").finish()
+    }
+}
+
+impl<D> Unpin for Recording<D> {}
+
+/// An error returned by [`Recording`] when the body was not read to completion.
+///
+/// The body's own error is passed on to whoever polls the [`RecordingBody`], so it is not
+/// included here.
+#[derive(Debug)]
+pub struct RecordError {
+    dropped: bool,
+}
+
+impl RecordError {
+    /// Returns `true` if the body was dropped before reaching its end, and `false` if it
+    /// yielded an error.
+    pub fn is_dropped(&self) -> bool {
+        self.dropped
+    }
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.dropped {
+            f.write_str("body was dropped before it was fully recorded")
+        } else {
+            f.write_str("body errored before it was fully recorded")
+        }
+    }
+}
+
+impl Error for RecordError {}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::Infallible, io};
+
+    use bytes::Bytes;
+    use http::HeaderMap;
+
+    use crate::{BodyExt, Full, StreamBody};
+
+    #[tokio::test]
+    async fn records_data_and_trailers() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("x-checksum", "abc".parse().unwrap());
+        let body = Full::<_, Infallible>::new(Bytes::from("hello ")).chain(
+            Full::<_, Infallible>::with_trailers(Bytes::from("world"), trailers),
+        );
+        let (body, recording) = body.record();
+
+        let forwarded = body.collect().await.unwrap();
+        assert_eq!(forwarded.to_bytes(), "hello world");
+
+        let recorded = recording.await.unwrap();
+        assert_eq!(recorded.trailers().unwrap()["x-checksum"], "abc");
+        assert_eq!(recorded.to_bytes(), "hello world");
+    }
+
+    #[tokio::test]
+    async fn error_and_drop_are_reported() {
+        let frames = vec![
+            Ok(http_body::Frame::data(Bytes::from("hello"))),
+            Err(io::Error::new(io::ErrorKind::Other, "oh no")),
+        ];
+        let (body, recording) = StreamBody::new(futures_util::stream::iter(frames)).record();
+        assert!(body.collect().await.is_err());
+        assert!(!recording.await.unwrap_err().is_dropped());
+
+        let (body, recording) = Full::<_, Infallible>::new(Bytes::from("hello")).record();
+        drop(body);
+        assert!(recording.await.unwrap_err().is_dropped());
+    }
+}
@@ -0,0 +1,249 @@
+use bytes::{Buf, Bytes};
+use http::HeaderMap;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    error::Error,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`replayable`] combinator.
+    ///
+    /// [`replayable`]: crate::BodyExt::replayable
+    #[derive(Clone, Debug)]
+    pub struct Replayable<B> {
+        #[pin]
+        inner: B,
+        data: Vec<Bytes>,
+        trailers: Option<HeaderMap>,
+        buffered: usize,
+        max_buffer: usize,
+        overflowed: bool,
+        pos: usize,
+        trailers_sent: bool,
+        inner_done: bool,
+    }
+}
+
+impl<B> Replayable<B> {
+    #[inline]
+    pub(crate) fn new(body: B, max_buffer: usize) -> Self {
+        Self {
+            inner: body,
+            data: Vec::new(),
+            trailers: None,
+            buffered: 0,
+            max_buffer,
+            overflowed: false,
+            pos: 0,
+            trailers_sent: false,
+            inner_done: false,
+        }
+    }
+
+    /// Restart the body from its first frame.
+    ///
+    /// The frames that have already been yielded are replayed from the buffer, after which the
+    /// inner body is polled again from where it left off. Fails with a [`NotReplayableError`]
+    /// once the body has yielded more than `max_buffer` bytes of data.
+    pub fn rewind(&mut self) -> Result<(), NotReplayableError> {
+        if self.overflowed {
+            return Err(NotReplayableError {
+                limit: self.max_buffer,
+            });
+        }
+        self.pos = 0;
+        self.trailers_sent = false;
+        Ok(())
+    }
+
+    /// Returns `true` if the body can still be rewound.
+    pub fn is_replayable(&self) -> bool {
+        !self.overflowed
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+}
+
+impl<B> Body for Replayable<B>
+where
+    B: Body,
+{
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(data) = this.data.get(*this.pos) {
+                *this.pos += 1;
+                return Poll::Ready(Some(Ok(Frame::data(data.clone()))));
+            }
+
+            if *this.inner_done {
+                if !*this.trailers_sent {
+                    *this.trailers_sent = true;
+                    if let Some(trailers) = this.trailers {
+                        return Poll::Ready(Some(Ok(Frame::trailers(trailers.clone()))));
+                    }
+                }
+                return Poll::Ready(None);
+            }
+
+            let frame = match this.inner.as_mut().poll_frame(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Some(Ok(frame))) => frame,
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(None) => {
+                    *this.inner_done = true;
+                    continue;
+                }
+            };
+
+            let frame = match frame.into_data() {
+                Ok(mut data) => {
+                    let data = data.copy_to_bytes(data.remaining());
+                    if !*this.overflowed {
+                        if data.len() > *this.max_buffer - *this.buffered {
+                            *this.overflowed = true;
+                            this.data.clear();
+                            this.trailers.take();
+                            *this.pos = 0;
+                        } else {
+                            *this.buffered += data.len();
+                            this.data.push(data.clone());
+                            *this.pos += 1;
+                        }
+                    }
+                    return Poll::Ready(Some(Ok(Frame::data(data))));
+                }
+                Err(frame) => frame,
+            };
+
+            if let Ok(trailers) = frame.into_trailers() {
+                *this.trailers_sent = true;
+                if !*this.overflowed {
+                    match this.trailers {
+                        Some(current) => current.extend(trailers.clone()),
+                        None => *this.trailers = Some(trailers.clone()),
+                    }
+                }
+                return Poll::Ready(Some(Ok(Frame::trailers(trailers))));
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.pos == self.data.len()
+            && self.inner_done
+            && (self.trailers_sent || self.trailers.is_none())
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        let replay = self.data[self.pos..]
+            .iter()
+            .map(|data| data.len() as u64)
+            .sum();
+        if self.inner_done {
+            SizeHint::with_exact(replay)
+        } else {
+            SizeHint::with_exact(replay) + self.inner.size_hint()
+        }
+    }
+}
+
+/// An error returned when rewinding a body that has outgrown its replay buffer.
+#[derive(Debug)]
+pub struct NotReplayableError {
+    limit: usize,
+}
+
+impl NotReplayableError {
+    /// Returns the size of the replay buffer that was exceeded.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl fmt::Display for NotReplayableError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "body exceeded the replay buffer of {} bytes", self.limit)
+    }
+}
+
+impl Error for NotReplayableError {}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+    use crate::{BodyExt, Full, StreamBody};
+
+    #[tokio::test]
+    async fn rewind_replays_frames() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("x-foo", "bar".parse().unwrap());
+        let mut body =
+            Full::<_, Infallible>::with_trailers(Bytes::from("hello"), trailers).replayable(16);
+
+        let first = (&mut body).collect().await.unwrap();
+        assert_eq!(first.trailers().unwrap()["x-foo"], "bar");
+        assert_eq!(first.to_bytes(), "hello");
+        assert!(body.is_end_stream());
+
+        body.rewind().unwrap();
+        assert_eq!(body.size_hint().exact(), Some(5));
+        let second = body.collect().await.unwrap();
+        assert_eq!(second.trailers().unwrap()["x-foo"], "bar");
+        assert_eq!(second.to_bytes(), "hello");
+    }
+
+    #[tokio::test]
+    async fn rewind_mid_body_continues_inner() {
+        let frames = [Bytes::from("hello"), Bytes::from(" world")];
+        let mut body = StreamBody::new(futures_util::stream::iter(
+            frames.map(Frame::data).map(Ok::<_, Infallible>),
+        ))
+        .replayable(16);
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hello"
+        );
+        body.rewind().unwrap();
+        assert_eq!(body.collect().await.unwrap().to_bytes(), "hello world");
+    }
+
+    #[tokio::test]
+    async fn overflow_is_not_replayable() {
+        let mut body = Full::<_, Infallible>::new(Bytes::from("hello world")).replayable(5);
+
+        assert_eq!(
+            (&mut body).collect().await.unwrap().to_bytes(),
+            "hello world"
+        );
+        assert!(!body.is_replayable());
+        assert_eq!(body.rewind().unwrap_err().limit(), 5);
+    }
+}
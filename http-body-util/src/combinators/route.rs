@@ -0,0 +1,133 @@
+use http::HeaderMap;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    any::type_name,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`route`] combinator.
+    ///
+    /// [`route`]: crate::BodyExt::route
+    #[derive(Clone, Copy)]
+    pub struct Route<B, F, G> {
+        #[pin]
+        inner: B,
+        on_data: F,
+        on_trailers: G,
+    }
+}
+
+impl<B, F, G> Route<B, F, G> {
+    #[inline]
+    pub(crate) fn new(body: B, on_data: F, on_trailers: G) -> Self {
+        Self {
+            inner: body,
+            on_data,
+            on_trailers,
+        }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B, F, G> Body for Route<B, F, G>
+where
+    B: Body,
+    F: FnMut(&B::Data),
+    G: FnMut(&HeaderMap),
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        match this.inner.poll_frame(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Ready(Some(Ok(frame))) => {
+                if let Some(data) = frame.data_ref() {
+                    (this.on_data)(data);
+                } else if let Some(trailers) = frame.trailers_ref() {
+                    (this.on_trailers)(trailers);
+                }
+                Poll::Ready(Some(Ok(frame)))
+            }
+            Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(err))),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl<B, F, G> fmt::Debug for Route<B, F, G>
+where
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Route")
+            .field("inner", &self.inner)
+            .field("on_data", &type_name::<F>())
+            .field("on_trailers", &type_name::<G>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::{Buf, Bytes};
+    use http::HeaderMap;
+
+    use crate::{BodyExt, Full};
+
+    #[tokio::test]
+    async fn routes_data_and_trailers_separately() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("x-checksum", "abc".parse().unwrap());
+
+        let mut data_len = 0;
+        let mut checksum = None;
+        let body = Full::<_, Infallible>::with_trailers(Bytes::from("hello"), trailers).route(
+            |data| data_len += data.remaining(),
+            |trailers| checksum = trailers.get("x-checksum").cloned(),
+        );
+
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.trailers().unwrap()["x-checksum"], "abc");
+        assert_eq!(collected.to_bytes(), "hello");
+        assert_eq!(data_len, 5);
+        assert_eq!(checksum.unwrap(), "abc");
+    }
+}
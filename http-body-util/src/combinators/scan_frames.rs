@@ -0,0 +1,140 @@
+use bytes::Buf;
+use http_body::{Body, Frame};
+use pin_project_lite::pin_project;
+use std::{
+    any::type_name,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`scan_frames`] combinator.
+    ///
+    /// [`scan_frames`]: crate::BodyExt::scan_frames
+    #[derive(Clone, Copy)]
+    pub struct ScanFrames<B, S, F> {
+        #[pin]
+        inner: B,
+        state: S,
+        f: F,
+    }
+}
+
+impl<B, S, F> ScanFrames<B, S, F> {
+    #[inline]
+    pub(crate) fn new(body: B, state: S, f: F) -> Self {
+        Self {
+            inner: body,
+            state,
+            f,
+        }
+    }
+
+    /// Get a reference to the current state
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B, S, F, B2> Body for ScanFrames<B, S, F>
+where
+    B: Body,
+    F: FnMut(&mut S, Frame<B::Data>) -> Option<Frame<B2>>,
+    B2: Buf,
+{
+    type Data = B2;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+        loop {
+            match this.inner.as_mut().poll_frame(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(Ok(frame))) => {
+                    if let Some(frame) = (this.f)(this.state, frame) {
+                        return Poll::Ready(Some(Ok(frame)));
+                    }
+                }
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+}
+
+impl<B, S, F> fmt::Debug for ScanFrames<B, S, F>
+where
+    B: fmt::Debug,
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ScanFrames")
+            .field("inner", &self.inner)
+            .field("state", &self.state)
+            .field("f", &type_name::<F>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::{Bytes, BytesMut};
+
+    use super::*;
+    use crate::{BodyExt, StreamBody};
+
+    #[tokio::test]
+    async fn reframes_lines() {
+        let frames = [
+            Bytes::from("he"),
+            Bytes::from("llo\nwor"),
+            Bytes::from("ld\n"),
+        ];
+        let body = StreamBody::new(futures_util::stream::iter(
+            frames.map(Frame::data).map(Ok::<_, Infallible>),
+        ))
+        .scan_frames(BytesMut::new(), |buf, frame| {
+            buf.extend_from_slice(frame.data_ref()?);
+            let end = buf.iter().position(|&b| b == b'\n')?;
+            Some(Frame::data(buf.split_to(end + 1).freeze()))
+        });
+        futures_util::pin_mut!(body);
+
+        let mut lines = Vec::new();
+        while let Some(frame) = body.frame().await {
+            lines.push(frame.unwrap().into_data().unwrap());
+        }
+        assert_eq!(lines, ["hello\n", "world\n"]);
+        assert!(body.state().is_empty());
+    }
+}
@@ -0,0 +1,145 @@
+use bytes::Buf;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`skip`] combinator.
+    ///
+    /// [`skip`]: crate::BodyExt::skip
+    #[derive(Clone, Copy, Debug)]
+    pub struct Skip<B> {
+        #[pin]
+        inner: B,
+        remaining: usize,
+    }
+}
+
+impl<B> Skip<B> {
+    #[inline]
+    pub(crate) fn new(body: B, n: usize) -> Self {
+        Self {
+            inner: body,
+            remaining: n,
+        }
+    }
+
+    /// Returns the number of bytes that are still to be skipped.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for Skip<B>
+where
+    B: Body,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        loop {
+            let frame = match this.inner.as_mut().poll_frame(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(Ok(frame))) => frame,
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+            };
+
+            if *this.remaining == 0 {
+                return Poll::Ready(Some(Ok(frame)));
+            }
+
+            match frame.into_data() {
+                Ok(mut data) => {
+                    let n = data.remaining().min(*this.remaining);
+                    data.advance(n);
+                    *this.remaining -= n;
+                    if data.has_remaining() {
+                        return Poll::Ready(Some(Ok(Frame::data(data))));
+                    }
+                }
+                Err(frame) => return Poll::Ready(Some(Ok(frame))),
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint().saturating_sub(self.remaining as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use http::HeaderMap;
+
+    use super::*;
+    use crate::{BodyExt, Full, StreamBody};
+
+    #[tokio::test]
+    async fn skips_across_frames() {
+        let frames = vec![
+            Frame::data(Bytes::from("hel")),
+            Frame::data(Bytes::from("lo world")),
+            Frame::trailers(HeaderMap::new()),
+        ];
+        let body = &mut StreamBody::new(futures_util::stream::iter(
+            frames.into_iter().map(Ok::<_, Infallible>),
+        ))
+        .skip(6);
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "world"
+        );
+        assert!(body.frame().await.unwrap().unwrap().is_trailers());
+        assert!(body.frame().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn size_hint_saturates() {
+        let body = Full::<_, Infallible>::new(Bytes::from("hello world")).skip(6);
+        assert_eq!(body.size_hint().exact(), Some(5));
+
+        let body = Full::<_, Infallible>::new(Bytes::from("hi")).skip(6);
+        assert_eq!(body.size_hint().exact(), Some(0));
+
+        let collected = body.collect().await.unwrap();
+        assert!(collected.to_bytes().is_empty());
+    }
+}
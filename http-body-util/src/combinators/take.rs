@@ -0,0 +1,136 @@
+use bytes::{Buf, Bytes};
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`take`] combinator.
+    ///
+    /// [`take`]: crate::BodyExt::take
+    #[derive(Clone, Copy, Debug)]
+    pub struct Take<B> {
+        #[pin]
+        inner: B,
+        remaining: usize,
+    }
+}
+
+impl<B> Take<B> {
+    #[inline]
+    pub(crate) fn new(body: B, n: usize) -> Self {
+        Self {
+            inner: body,
+            remaining: n,
+        }
+    }
+
+    /// Returns the number of bytes that may still be yielded.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for Take<B>
+where
+    B: Body,
+{
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+
+        if *this.remaining == 0 {
+            return Poll::Ready(None);
+        }
+
+        let frame = match this.inner.poll_frame(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Ready(Some(Ok(frame))) => frame,
+            Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+        };
+
+        let remaining = this.remaining;
+        let frame = frame.map_data(|mut data| {
+            let n = data.remaining().min(*remaining);
+            *remaining -= n;
+            data.copy_to_bytes(n)
+        });
+
+        Poll::Ready(Some(Ok(frame)))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.remaining == 0 || self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint().clamp_upper(self.remaining as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+    use crate::{BodyExt, Full, StreamBody};
+
+    #[tokio::test]
+    async fn truncates_crossing_frame() {
+        let frames =
+            ["hello ", "world", "!"].map(|s| Ok::<_, Infallible>(Frame::data(Bytes::from(s))));
+        let body = &mut StreamBody::new(futures_util::stream::iter(frames)).take(8);
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hello "
+        );
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "wo"
+        );
+        assert!(body.is_end_stream());
+        assert!(body.frame().await.is_none());
+        assert_eq!(body.size_hint().exact(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn size_hint_is_capped() {
+        let body = Full::<_, Infallible>::new(Bytes::from("hello world")).take(5);
+        assert_eq!(body.size_hint().exact(), Some(5));
+
+        let body = Full::<_, Infallible>::new(Bytes::from("hi")).take(5);
+        assert_eq!(body.size_hint().exact(), Some(2));
+
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), "hi");
+    }
+}
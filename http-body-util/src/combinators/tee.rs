@@ -0,0 +1,124 @@
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    any::type_name,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`tee`] combinator.
+    ///
+    /// [`tee`]: crate::BodyExt::tee
+    #[derive(Clone, Copy)]
+    pub struct Tee<B, F> {
+        #[pin]
+        inner: B,
+        sink: F
+    }
+}
+
+impl<B, F> Tee<B, F> {
+    #[inline]
+    pub(crate) fn new(body: B, sink: F) -> Self {
+        Self { inner: body, sink }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B, F> Body for Tee<B, F>
+where
+    B: Body,
+    B::Data: AsRef<[u8]>,
+    F: FnMut(&[u8]),
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        match this.inner.poll_frame(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Ready(Some(Ok(frame))) => {
+                if let Some(data) = frame.data_ref() {
+                    (this.sink)(data.as_ref());
+                }
+                Poll::Ready(Some(Ok(frame)))
+            }
+            Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(err))),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl<B, F> fmt::Debug for Tee<B, F>
+where
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Tee")
+            .field("inner", &self.inner)
+            .field("sink", &type_name::<F>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use http::HeaderMap;
+    use http_body::Frame;
+
+    use crate::{BodyExt, StreamBody};
+
+    #[tokio::test]
+    async fn copies_data_frames() {
+        let frames = [
+            Frame::data(Bytes::from("hello ")),
+            Frame::data(Bytes::from("world")),
+            Frame::trailers(HeaderMap::new()),
+        ];
+        let mut copy = Vec::new();
+
+        let body = StreamBody::new(futures_util::stream::iter(frames.map(Ok::<_, Infallible>)))
+            .tee(|chunk| copy.extend_from_slice(chunk));
+
+        let collected = body.collect().await.unwrap();
+        assert!(collected.trailers().is_some());
+        assert_eq!(collected.to_bytes(), "hello world");
+        assert_eq!(copy, b"hello world");
+    }
+}
@@ -0,0 +1,127 @@
+use bytes::Buf;
+use futures_core::ready;
+use http_body::{Body, Frame};
+use pin_project_lite::pin_project;
+use std::{
+    any::type_name,
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`then_frame`] combinator.
+    ///
+    /// [`then_frame`]: crate::BodyExt::then_frame
+    #[derive(Clone)]
+    pub struct ThenFrame<B, F, Fut> {
+        #[pin]
+        inner: B,
+        f: F,
+        #[pin]
+        pending: Option<Fut>,
+    }
+}
+
+impl<B, F, Fut> ThenFrame<B, F, Fut> {
+    #[inline]
+    pub(crate) fn new(body: B, f: F) -> Self {
+        Self {
+            inner: body,
+            f,
+            pending: None,
+        }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+}
+
+impl<B, F, Fut, B2, E> Body for ThenFrame<B, F, Fut>
+where
+    B: Body,
+    B::Error: Into<E>,
+    F: FnMut(Frame<B::Data>) -> Fut,
+    Fut: Future<Output = Result<Frame<B2>, E>>,
+    B2: Buf,
+{
+    type Data = B2;
+    type Error = E;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(fut) = this.pending.as_mut().as_pin_mut() {
+                let res = ready!(fut.poll(cx));
+                this.pending.set(None);
+                return Poll::Ready(Some(res));
+            }
+
+            match ready!(this.inner.as_mut().poll_frame(cx)) {
+                Some(Ok(frame)) => this.pending.set(Some((this.f)(frame))),
+                Some(Err(err)) => return Poll::Ready(Some(Err(err.into()))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.pending.is_none() && self.inner.is_end_stream()
+    }
+}
+
+impl<B, F, Fut> fmt::Debug for ThenFrame<B, F, Fut>
+where
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ThenFrame")
+            .field("inner", &self.inner)
+            .field("f", &type_name::<F>())
+            .field(
+                "pending",
+                &self.pending.as_ref().map(|_| type_name::<Fut>()),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use http::HeaderMap;
+
+    use crate::{BodyExt, Full};
+
+    #[tokio::test]
+    async fn transforms_frames_in_order() {
+        let body = Full::<_, Infallible>::with_trailers(Bytes::from("hello"), HeaderMap::new())
+            .then_frame(|frame| async move {
+                tokio::task::yield_now().await;
+                Ok::<_, Infallible>(frame.map_data(|data| Bytes::from(data.to_ascii_uppercase())))
+            });
+
+        let collected = body.collect().await.unwrap();
+        assert!(collected.trailers().is_some());
+        assert_eq!(collected.to_bytes(), "HELLO");
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use bytes::{Buf, Bytes};
+use futures_core::ready;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use tokio::time::{sleep_until, Instant, Sleep};
+
+const WINDOW: Duration = Duration::from_secs(1);
+
+pin_project! {
+    /// Body returned by the [`throttle`] combinator.
+    ///
+    /// [`throttle`]: crate::BodyExt::throttle
+    #[derive(Debug)]
+    pub struct Throttle<B> {
+        #[pin]
+        inner: B,
+        #[pin]
+        sleep: Option<Sleep>,
+        bytes_per_sec: usize,
+        // Data chunks emitted within the last `WINDOW`, oldest first.
+        sent: VecDeque<(Instant, usize)>,
+        sent_bytes: usize,
+        pending: Bytes,
+    }
+}
+
+impl<B> Throttle<B> {
+    #[inline]
+    pub(crate) fn new(body: B, bytes_per_sec: usize) -> Self {
+        assert!(bytes_per_sec > 0, "bytes_per_sec must be greater than zero");
+        Self {
+            inner: body,
+            sleep: None,
+            bytes_per_sec,
+            sent: VecDeque::new(),
+            sent_bytes: 0,
+            pending: Bytes::new(),
+        }
+    }
+
+    /// Returns the maximum number of bytes emitted per second.
+    pub fn bytes_per_sec(&self) -> usize {
+        self.bytes_per_sec
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    ///
+    /// Any data already read from the inner body but not yet yielded is lost.
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for Throttle<B>
+where
+    B: Body,
+{
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                ready!(sleep.poll(cx));
+                this.sleep.set(None);
+            }
+
+            let now = Instant::now();
+            while let Some(&(at, len)) = this.sent.front() {
+                if at + WINDOW > now {
+                    break;
+                }
+                this.sent.pop_front();
+                *this.sent_bytes -= len;
+            }
+
+            let allowance = *this.bytes_per_sec - *this.sent_bytes;
+            if allowance == 0 {
+                // The allowance is used up, so at least one chunk is still inside the window.
+                let (oldest, _) = this.sent[0];
+                this.sleep.set(Some(sleep_until(oldest + WINDOW)));
+                continue;
+            }
+
+            if this.pending.is_empty() {
+                let frame = match ready!(this.inner.as_mut().poll_frame(cx)) {
+                    Some(Ok(frame)) => frame,
+                    Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+                    None => return Poll::Ready(None),
+                };
+                match frame.into_data() {
+                    Ok(mut data) => *this.pending = data.copy_to_bytes(data.remaining()),
+                    Err(frame) => {
+                        let frame = frame.map_data(|mut data| data.copy_to_bytes(data.remaining()));
+                        return Poll::Ready(Some(Ok(frame)));
+                    }
+                }
+            }
+
+            let chunk = this.pending.split_to(this.pending.len().min(allowance));
+            this.sent.push_back((now, chunk.len()));
+            *this.sent_bytes += chunk.len();
+            return Poll::Ready(Some(Ok(Frame::data(chunk))));
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.pending.is_empty() && self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::with_exact(self.pending.len() as u64) + self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use futures_util::StreamExt;
+    use http::HeaderMap;
+
+    use super::*;
+    use crate::{BodyExt, Full, StreamBody};
+
+    #[tokio::test(start_paused = true)]
+    async fn splits_and_delays_oversized_frames() {
+        let body = Full::<_, Infallible>::with_trailers(Bytes::from(vec![0; 25]), HeaderMap::new())
+            .throttle(10);
+        let mut body = Box::pin(body);
+        assert_eq!(body.size_hint().exact(), Some(25));
+
+        let start = Instant::now();
+        let mut chunks = Vec::new();
+        while let Some(frame) = body.frame().await {
+            let frame = frame.unwrap();
+            match frame.into_data() {
+                Ok(data) => chunks.push((data.len(), start.elapsed().as_secs())),
+                Err(frame) => assert!(frame.is_trailers()),
+            }
+        }
+
+        assert_eq!(chunks, [(10, 0), (10, 1), (5, 2)]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn window_slides_with_emitted_data() {
+        let frames = Box::pin(futures_util::stream::iter([900, 0]).then(|ms| async move {
+            tokio::time::sleep(Duration::from_millis(ms)).await;
+            Ok::<_, Infallible>(Frame::data(Bytes::from(vec![0; 10])))
+        }));
+        let mut body = Box::pin(StreamBody::new(frames).throttle(10));
+
+        let start = Instant::now();
+        let mut chunks = Vec::new();
+        while let Some(frame) = body.frame().await {
+            let data = frame.unwrap().into_data().unwrap();
+            chunks.push((data.len(), start.elapsed().as_millis()));
+        }
+
+        // The second chunk waits a full second after the first, even though the first arrived
+        // late in the second after polling started.
+        assert_eq!(chunks, [(10, 900), (10, 1900)]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn size_hint_includes_pending_bytes() {
+        let mut body = Box::pin(Full::<_, Infallible>::new(Bytes::from("hello")).throttle(2));
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "he"
+        );
+        assert_eq!(body.size_hint().exact(), Some(3));
+        assert!(!body.is_end_stream());
+    }
+}
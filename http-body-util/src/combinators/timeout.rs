@@ -0,0 +1,154 @@
+use std::{
+    error::Error,
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_core::ready;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use tokio::time::{sleep, Sleep};
+
+pin_project! {
+    /// Body returned by the [`timeout`] combinator.
+    ///
+    /// [`timeout`]: crate::BodyExt::timeout
+    #[derive(Debug)]
+    pub struct Timeout<B> {
+        #[pin]
+        inner: B,
+        #[pin]
+        sleep: Option<Sleep>,
+        duration: Duration,
+    }
+}
+
+impl<B> Timeout<B> {
+    #[inline]
+    pub(crate) fn new(body: B, duration: Duration) -> Self {
+        Self {
+            inner: body,
+            sleep: None,
+            duration,
+        }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for Timeout<B>
+where
+    B: Body,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    type Data = B::Data;
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        if let Poll::Ready(frame) = this.inner.poll_frame(cx) {
+            this.sleep.set(None);
+            return Poll::Ready(frame.map(|res| res.map_err(Into::into)));
+        }
+
+        if this.sleep.is_none() {
+            this.sleep.set(Some(sleep(*this.duration)));
+        }
+        ready!(this.sleep.as_mut().as_pin_mut().unwrap().poll(cx));
+        this.sleep.set(None);
+
+        Poll::Ready(Some(Err(BodyTimeoutError {
+            duration: *this.duration,
+        }
+        .into())))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// An error returned when a body does not yield a frame within the configured timeout.
+#[derive(Debug)]
+pub struct BodyTimeoutError {
+    duration: Duration,
+}
+
+impl BodyTimeoutError {
+    /// Returns the timeout that elapsed.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl fmt::Display for BodyTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("body timed out waiting for a frame")
+    }
+}
+
+impl Error for BodyTimeoutError {}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use futures_util::StreamExt;
+
+    use super::*;
+    use crate::{BodyExt, StreamBody};
+
+    #[tokio::test(start_paused = true)]
+    async fn stalled_body_times_out() {
+        let inner = StreamBody::new(futures_util::stream::pending::<
+            Result<Frame<Bytes>, Infallible>,
+        >());
+        let mut body = Box::pin(inner.timeout(Duration::from_secs(1)));
+
+        let err = body.frame().await.unwrap().unwrap_err();
+        let err = err.downcast_ref::<BodyTimeoutError>().unwrap();
+        assert_eq!(err.duration(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn timer_resets_on_each_frame() {
+        let stream = futures_util::stream::iter(0..3).then(|_| async {
+            tokio::time::sleep(Duration::from_millis(600)).await;
+            Ok::<_, Infallible>(Frame::data(Bytes::from("a")))
+        });
+        let body = StreamBody::new(Box::pin(stream)).timeout(Duration::from_secs(1));
+
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), "aaa");
+    }
+}
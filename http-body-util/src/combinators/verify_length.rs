@@ -0,0 +1,213 @@
+use bytes::Buf;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    error::Error,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// Body returned by the [`verify_length`] combinator.
+    ///
+    /// This body will return an error if the wrapped body yields more or fewer data bytes than
+    /// expected.
+    ///
+    /// [`verify_length`]: crate::BodyExt::verify_length
+    #[derive(Clone, Copy, Debug)]
+    pub struct VerifyLength<B> {
+        expected: u64,
+        received: u64,
+        done: bool,
+        #[pin]
+        inner: B,
+    }
+}
+
+impl<B> VerifyLength<B> {
+    #[inline]
+    pub(crate) fn new(inner: B, expected: u64) -> Self {
+        Self {
+            expected,
+            received: 0,
+            done: false,
+            inner,
+        }
+    }
+
+    /// Returns the number of data bytes yielded so far.
+    pub fn received(&self) -> u64 {
+        self.received
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().inner
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for VerifyLength<B>
+where
+    B: Body,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    type Data = B::Data;
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        let res = match this.inner.poll_frame(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(None) => {
+                *this.done = true;
+                if *this.received < *this.expected {
+                    Some(Err(LengthMismatch {
+                        expected: *this.expected,
+                        actual: *this.received,
+                    }
+                    .into()))
+                } else {
+                    None
+                }
+            }
+            Poll::Ready(Some(Ok(frame))) => {
+                if let Some(data) = frame.data_ref() {
+                    *this.received = this.received.saturating_add(data.remaining() as u64);
+                }
+                if *this.received > *this.expected {
+                    *this.done = true;
+                    Some(Err(LengthMismatch {
+                        expected: *this.expected,
+                        actual: *this.received,
+                    }
+                    .into()))
+                } else {
+                    Some(Ok(frame))
+                }
+            }
+            Poll::Ready(Some(Err(err))) => Some(Err(err.into())),
+        };
+
+        Poll::Ready(res)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.done || (self.received == self.expected && self.inner.is_end_stream())
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        if self.done {
+            return SizeHint::with_exact(0);
+        }
+        self.inner.size_hint()
+    }
+}
+
+/// An error returned when a body yields a different number of data bytes than expected.
+#[derive(Debug)]
+pub struct LengthMismatch {
+    expected: u64,
+    actual: u64,
+}
+
+impl LengthMismatch {
+    /// Returns the number of bytes the body was expected to yield.
+    pub fn expected(&self) -> u64 {
+        self.expected
+    }
+
+    /// Returns the number of bytes the body yielded.
+    ///
+    /// When the body yielded too much data, this counts up to and including the frame that
+    /// exceeded the expected length, since the rest of the body is not polled.
+    pub fn actual(&self) -> u64 {
+        self.actual
+    }
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "body length mismatch: expected {} bytes, got {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl Error for LengthMismatch {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BodyExt, Full, StreamBody};
+    use bytes::Bytes;
+    use std::convert::Infallible;
+
+    fn body_from_chunks(chunks: &[&'static str]) -> impl Body<Data = Bytes, Error = Infallible> {
+        let frames: Vec<_> = chunks
+            .iter()
+            .map(|s| Ok(Frame::data(Bytes::from_static(s.as_bytes()))))
+            .collect();
+        StreamBody::new(futures_util::stream::iter(frames))
+    }
+
+    #[tokio::test]
+    async fn exact_length_is_okay() {
+        let body = Full::<_, Infallible>::new(Bytes::from("hello")).verify_length(5);
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), "hello");
+    }
+
+    #[tokio::test]
+    async fn short_body_errors_at_end() {
+        let body = &mut body_from_chunks(&["hel", "lo"]).verify_length(8);
+
+        body.frame().await.unwrap().unwrap();
+        body.frame().await.unwrap().unwrap();
+        assert!(!body.is_end_stream());
+        let error = body.frame().await.unwrap().unwrap_err();
+        let error = error.downcast_ref::<LengthMismatch>().unwrap();
+        assert_eq!((error.expected(), error.actual()), (8, 5));
+        assert_eq!(
+            error.to_string(),
+            "body length mismatch: expected 8 bytes, got 5"
+        );
+        assert!(body.is_end_stream());
+        assert!(body.frame().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn long_body_errors_immediately() {
+        let body = &mut body_from_chunks(&["hel", "lo", " world"]).verify_length(4);
+
+        body.frame().await.unwrap().unwrap();
+        let error = body.frame().await.unwrap().unwrap_err();
+        let error = error.downcast_ref::<LengthMismatch>().unwrap();
+        assert_eq!((error.expected(), error.actual()), (4, 5));
+        assert!(body.frame().await.is_none());
+    }
+}
@@ -13,9 +13,11 @@ pin_project! {
     /// Adds trailers to a body.
     ///
     /// See [`BodyExt::with_trailers`] for more details.
+    #[derive(Clone)]
     pub struct WithTrailers<T, F> {
         #[pin]
         state: State<T, F>,
+        merge: TrailerMerge,
     }
 }
 
@@ -26,12 +28,46 @@ impl<T, F> WithTrailers<T, F> {
                 body,
                 trailers: Some(trailers),
             },
+            merge: TrailerMerge::Append,
         }
     }
+
+    /// Set how the added trailers are combined with trailers yielded by the body itself.
+    ///
+    /// Defaults to [`TrailerMerge::Append`].
+    pub fn trailer_merge(mut self, merge: TrailerMerge) -> Self {
+        self.merge = merge;
+        self
+    }
+}
+
+/// How [`WithTrailers`] combines the added trailers with trailers yielded by the body.
+///
+/// This only matters when both are present. If only one of them is, it is yielded as is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrailerMerge {
+    /// Merge the added trailers into the body's trailers, yielding a single trailers frame.
+    ///
+    /// Where both contain the same header name, the added values replace the body's values.
+    Append,
+    /// Discard the body's trailers and yield only the added trailers.
+    Replace,
+    /// Yield the body's trailers, followed by the added trailers as a second trailers frame.
+    ///
+    /// HTTP/2 only allows a single trailers block, so this should only be used when the
+    /// receiver expects several.
+    EmitSeparate,
+}
+
+impl Default for TrailerMerge {
+    fn default() -> Self {
+        TrailerMerge::Append
+    }
 }
 
 pin_project! {
     #[project = StateProj]
+    #[derive(Clone)]
     enum State<T, F> {
         PollBody {
             #[pin]
@@ -43,6 +79,9 @@ pin_project! {
             trailers: F,
             prev_trailers: Option<HeaderMap>,
         },
+        EmitTrailers {
+            trailers: Option<HeaderMap>,
+        },
         Done,
     }
 }
@@ -90,18 +129,38 @@ where
                 } => {
                     let trailers = ready!(trailers.poll(cx)?);
                     match (trailers, prev_trailers.take()) {
-                        (None, None) => return Poll::Ready(None),
-                        (None, Some(trailers)) | (Some(trailers), None) => {
+                        (None, None) => {
                             this.state.set(State::Done);
-                            return Poll::Ready(Some(Ok(Frame::trailers(trailers))));
+                            return Poll::Ready(None);
                         }
-                        (Some(new_trailers), Some(mut prev_trailers)) => {
-                            prev_trailers.extend(new_trailers);
+                        (None, Some(trailers)) | (Some(trailers), None) => {
                             this.state.set(State::Done);
-                            return Poll::Ready(Some(Ok(Frame::trailers(prev_trailers))));
+                            return Poll::Ready(Some(Ok(Frame::trailers(trailers))));
                         }
+                        (Some(new_trailers), Some(mut prev_trailers)) => match this.merge {
+                            TrailerMerge::Append => {
+                                prev_trailers.extend(new_trailers);
+                                this.state.set(State::Done);
+                                return Poll::Ready(Some(Ok(Frame::trailers(prev_trailers))));
+                            }
+                            TrailerMerge::Replace => {
+                                this.state.set(State::Done);
+                                return Poll::Ready(Some(Ok(Frame::trailers(new_trailers))));
+                            }
+                            TrailerMerge::EmitSeparate => {
+                                this.state.set(State::EmitTrailers {
+                                    trailers: Some(new_trailers),
+                                });
+                                return Poll::Ready(Some(Ok(Frame::trailers(prev_trailers))));
+                            }
+                        },
                     }
                 }
+                StateProj::EmitTrailers { trailers } => {
+                    let trailers = trailers.take().unwrap();
+                    this.state.set(State::Done);
+                    return Poll::Ready(Some(Ok(Frame::trailers(trailers))));
+                }
                 StateProj::Done => {
                     return Poll::Ready(None);
                 }
@@ -109,11 +168,18 @@ where
         }
     }
 
+    #[inline]
+    fn is_end_stream(&self) -> bool {
+        matches!(self.state, State::Done)
+    }
+
     #[inline]
     fn size_hint(&self) -> http_body::SizeHint {
         match &self.state {
             State::PollBody { body, .. } => body.size_hint(),
-            State::PollTrailers { .. } | State::Done => Default::default(),
+            State::PollTrailers { .. } | State::EmitTrailers { .. } | State::Done => {
+                http_body::SizeHint::with_exact(0)
+            }
         }
     }
 }
@@ -204,10 +270,108 @@ mod tests {
         assert!(unwrap_ready(body.as_mut().poll_frame(&mut cx)).is_none());
     }
 
+    #[tokio::test]
+    async fn merged_trailers_replace_same_name() {
+        let mut inner_trailers = HeaderMap::new();
+        inner_trailers.insert("foo", HeaderValue::from_static("inner"));
+        inner_trailers.insert("bar", HeaderValue::from_static("inner"));
+
+        let mut trailers = HeaderMap::new();
+        trailers.insert("foo", HeaderValue::from_static("outer"));
+
+        let body = Full::<_, Infallible>::with_trailers(Bytes::from("hello"), inner_trailers)
+            .with_trailers(std::future::ready(Some(Ok::<_, Infallible>(trailers))));
+
+        let collected = body.collect().await.unwrap();
+        let trailers = collected.trailers().unwrap();
+        assert_eq!(trailers.get_all("foo").iter().count(), 1);
+        assert_eq!(trailers["foo"], "outer");
+        assert_eq!(trailers["bar"], "inner");
+    }
+
+    #[tokio::test]
+    async fn trailer_merge_policies() {
+        let trailers = |value: &'static str| {
+            let mut trailers = HeaderMap::new();
+            trailers.insert("foo", HeaderValue::from_static(value));
+            trailers
+        };
+        let body = |merge| {
+            Full::<_, Infallible>::with_trailers(Bytes::from("hello"), trailers("inner"))
+                .with_trailers(std::future::ready(Some(Ok(trailers("outer")))))
+                .trailer_merge(merge)
+        };
+
+        let collected = body(TrailerMerge::Replace).collect().await.unwrap();
+        assert_eq!(collected.trailers().unwrap()["foo"], "outer");
+
+        let mut body = body(TrailerMerge::EmitSeparate);
+        assert!(body.frame().await.unwrap().unwrap().is_data());
+        let first = body
+            .frame()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_trailers()
+            .unwrap();
+        assert_eq!(first["foo"], "inner");
+        assert!(!body.is_end_stream());
+        let second = body
+            .frame()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_trailers()
+            .unwrap();
+        assert_eq!(second["foo"], "outer");
+        assert!(body.is_end_stream());
+        assert!(body.frame().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn size_hint_stays_exact() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("foo", HeaderValue::from_static("bar"));
+        let mut body = Full::<_, Infallible>::with_trailers(Bytes::from("hello"), trailers);
+
+        assert_eq!(body.size_hint().exact(), Some(5));
+        assert!(body.frame().await.unwrap().unwrap().is_data());
+        assert!(body.frame().await.unwrap().unwrap().is_trailers());
+        assert_eq!(body.size_hint().exact(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn end_stream_after_trailers() {
+        let body = Empty::<Bytes>::new().with_trailers(std::future::ready(None));
+
+        futures_util::pin_mut!(body);
+        let waker = futures_util::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert!(!body.is_end_stream());
+        assert!(unwrap_ready(body.as_mut().poll_frame(&mut cx)).is_none());
+        assert!(body.is_end_stream());
+        assert!(unwrap_ready(body.as_mut().poll_frame(&mut cx)).is_none());
+    }
+
     fn unwrap_ready<T>(poll: Poll<T>) -> T {
         match poll {
             Poll::Ready(t) => t,
             Poll::Pending => panic!("pending"),
         }
     }
+
+    #[tokio::test]
+    async fn cloned_body_yields_same_frames() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("x-done", HeaderValue::from_static("1"));
+        let template = Full::<_, Infallible>::new(Bytes::from("hello"))
+            .with_trailers(std::future::ready(Some(Ok(trailers))));
+
+        for body in [template.clone(), template] {
+            let collected = body.collect().await.unwrap();
+            assert_eq!(collected.trailers().unwrap()["x-done"], "1");
+            assert_eq!(collected.to_bytes(), "hello");
+        }
+    }
 }
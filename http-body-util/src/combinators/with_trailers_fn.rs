@@ -0,0 +1,176 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use http::HeaderMap;
+use http_body::{Body, Frame};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Adds lazily computed trailers to a body.
+    ///
+    /// See [`BodyExt::with_trailers_fn`] for more details.
+    ///
+    /// [`BodyExt::with_trailers_fn`]: crate::BodyExt::with_trailers_fn
+    #[derive(Clone)]
+    pub struct WithTrailersFn<T, F, Fut> {
+        #[pin]
+        state: State<T, F, Fut>,
+    }
+}
+
+impl<T, F, Fut> WithTrailersFn<T, F, Fut> {
+    pub(crate) fn new(body: T, f: F) -> Self {
+        Self {
+            state: State::PollBody { body, f: Some(f) },
+        }
+    }
+}
+
+pin_project! {
+    #[project = StateProj]
+    #[derive(Clone)]
+    enum State<T, F, Fut> {
+        PollBody {
+            #[pin]
+            body: T,
+            f: Option<F>,
+        },
+        PollTrailers {
+            #[pin]
+            trailers: Fut,
+            prev_trailers: Option<HeaderMap>,
+        },
+        Done,
+    }
+}
+
+impl<T, F, Fut> Body for WithTrailersFn<T, F, Fut>
+where
+    T: Body,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Option<HeaderMap>>,
+{
+    type Data = T::Data;
+    type Error = T::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        loop {
+            let mut this = self.as_mut().project();
+
+            match this.state.as_mut().project() {
+                StateProj::PollBody { body, f } => {
+                    let prev_trailers = match ready!(body.poll_frame(cx)?) {
+                        Some(frame) => match frame.into_trailers() {
+                            Ok(prev_trailers) => Some(prev_trailers),
+                            Err(frame) => return Poll::Ready(Some(Ok(frame))),
+                        },
+                        None => None,
+                    };
+                    let trailers = (f.take().unwrap())();
+                    this.state.set(State::PollTrailers {
+                        trailers,
+                        prev_trailers,
+                    });
+                }
+                StateProj::PollTrailers {
+                    trailers,
+                    prev_trailers,
+                } => {
+                    let trailers = ready!(trailers.poll(cx));
+                    let trailers = match (trailers, prev_trailers.take()) {
+                        (None, None) => None,
+                        (None, Some(trailers)) | (Some(trailers), None) => Some(trailers),
+                        (Some(new_trailers), Some(mut prev_trailers)) => {
+                            prev_trailers.extend(new_trailers);
+                            Some(prev_trailers)
+                        }
+                    };
+                    this.state.set(State::Done);
+                    return Poll::Ready(trailers.map(|trailers| Ok(Frame::trailers(trailers))));
+                }
+                StateProj::Done => {
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn is_end_stream(&self) -> bool {
+        matches!(self.state, State::Done)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> http_body::SizeHint {
+        match &self.state {
+            State::PollBody { body, .. } => body.size_hint(),
+            State::PollTrailers { .. } | State::Done => http_body::SizeHint::with_exact(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    use bytes::Bytes;
+    use http::HeaderValue;
+
+    use crate::{BodyExt, Full, StreamBody};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn computes_trailers_after_body() {
+        let len = Arc::new(AtomicUsize::new(0));
+        let counted = len.clone();
+
+        let frames = [Bytes::from("hello"), Bytes::from(" world")];
+        let body = StreamBody::new(futures_util::stream::iter(
+            frames.map(Frame::data).map(Ok::<_, Infallible>),
+        ))
+        .map_frame(move |frame| {
+            if let Some(data) = frame.data_ref() {
+                counted.fetch_add(data.len(), Ordering::SeqCst);
+            }
+            frame
+        })
+        .with_trailers_fn(move || async move {
+            let mut trailers = HeaderMap::new();
+            let len = len.load(Ordering::SeqCst).to_string();
+            trailers.insert("x-len", HeaderValue::from_str(&len).unwrap());
+            Some(trailers)
+        });
+
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.trailers().unwrap()["x-len"], "11");
+        assert_eq!(collected.to_bytes(), "hello world");
+    }
+
+    #[tokio::test]
+    async fn no_trailers() {
+        let mut body = Full::<_, Infallible>::new(Bytes::from("hello"))
+            .with_trailers_fn(|| std::future::ready(None));
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hello"
+        );
+        assert!(body.frame().await.is_none());
+        assert!(body.is_end_stream());
+        assert_eq!(body.size_hint().exact(), Some(0));
+    }
+}
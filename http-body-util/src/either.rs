@@ -21,10 +21,28 @@ pub enum Either<L, R> {
 }
 
 impl<L, R> Either<L, R> {
+    /// Create a `Left` value, inferring the type of the `Right` case.
+    pub fn left(left: L) -> Self {
+        Either::Left(left)
+    }
+
+    /// Create a `Right` value, inferring the type of the `Left` case.
+    pub fn right(right: R) -> Self {
+        Either::Right(right)
+    }
+
+    /// Swap the two cases, turning a `Left` into a `Right` and vice versa.
+    pub fn flip(self) -> Either<R, L> {
+        match self {
+            Either::Left(left) => Either::Right(left),
+            Either::Right(right) => Either::Left(right),
+        }
+    }
+
     /// This function is part of the generated code from `pin-project-lite`,
     /// for a more in depth explanation and the rest of the generated code refer
     /// to the [`proj`] module.
-    pub(crate) fn project(self: Pin<&mut Self>) -> EitherProj<L, R> {
+    pub(crate) fn project(self: Pin<&mut Self>) -> EitherProj<'_, L, R> {
         unsafe {
             match self.get_unchecked_mut() {
                 Self::Left(left) => EitherProj::Left(Pin::new_unchecked(left)),
@@ -32,6 +50,40 @@ impl<L, R> Either<L, R> {
             }
         }
     }
+
+    /// Apply `f` to the value if this is a `Left`, leaving a `Right` untouched.
+    pub fn map_left<F, T>(self, f: F) -> Either<T, R>
+    where
+        F: FnOnce(L) -> T,
+    {
+        match self {
+            Either::Left(left) => Either::Left(f(left)),
+            Either::Right(right) => Either::Right(right),
+        }
+    }
+
+    /// Apply `f` to the value if this is a `Right`, leaving a `Left` untouched.
+    pub fn map_right<F, T>(self, f: F) -> Either<L, T>
+    where
+        F: FnOnce(R) -> T,
+    {
+        match self {
+            Either::Left(left) => Either::Left(left),
+            Either::Right(right) => Either::Right(f(right)),
+        }
+    }
+
+    /// Fold both cases into a single value, applying `f` to a `Left` or `g` to a `Right`.
+    pub fn either<F, G, T>(self, f: F, g: G) -> T
+    where
+        F: FnOnce(L) -> T,
+        G: FnOnce(R) -> T,
+    {
+        match self {
+            Either::Left(left) => f(left),
+            Either::Right(right) => g(right),
+        }
+    }
 }
 
 impl<L> Either<L, L> {
@@ -138,6 +190,7 @@ pub(crate) mod proj {
         }
         impl<'__pin, L, R> Unpin for Either<L, R> where __Origin<'__pin, L, R>: Unpin {}
 
+        #[allow(dead_code)]
         trait MustNotImplDrop {}
         #[allow(drop_bounds)]
         impl<T: Drop> MustNotImplDrop for T {}
@@ -180,6 +233,27 @@ mod tests {
         assert!(value.frame().await.is_none());
     }
 
+    #[test]
+    fn map_and_fold() {
+        let left = Either::<i32, &str>::Left(2).map_left(|n| n * 2);
+        assert_eq!(left.either(|n| n.to_string(), str::to_owned), "4");
+
+        let right = Either::<i32, &str>::Right("hi").map_right(str::len);
+        assert_eq!(right.map_left(|n| n as usize).into_inner(), 2);
+    }
+
+    #[tokio::test]
+    async fn constructors_infer_other_side() {
+        let body = if true {
+            Either::left(Full::<_, Infallible>::new(&b"hello"[..]))
+        } else {
+            Either::right(Empty::<_, Infallible>::new())
+        };
+
+        let collected = body.flip().collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), "hello");
+    }
+
     #[test]
     fn into_inner() {
         let a = Either::<i32, i32>::Left(2);
@@ -0,0 +1,230 @@
+//! Sum types with more than two cases, generated by the [`either_n!`] macro.
+//!
+//! Each type mirrors [`Either`]: it implements [`Body`] by delegating to the
+//! active variant, unifies errors into `Box<dyn Error + Send + Sync>`, and has
+//! an accompanying projection module generated in the same way as
+//! [`either::proj`].
+//!
+//! [`Either`]: crate::Either
+//! [`either::proj`]: crate::either::proj
+
+use std::error::Error;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Buf;
+use http_body::{Body, Frame, SizeHint};
+
+/// Expands to `$ty`, ignoring `$_ignored`. Used to repeat a type once per
+/// variant.
+macro_rules! replace_ty {
+    ($_ignored:ident, $ty:ty) => {
+        $ty
+    };
+}
+
+macro_rules! either_n {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $proj_mod:ident::$proj:ident { $($variant:ident),+ $(,)? }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy)]
+        pub enum $name<$($variant),+> {
+            $(
+                #[doc = concat!("A value of type `", stringify!($variant), "`")]
+                $variant($variant),
+            )+
+        }
+
+        impl<$($variant),+> $name<$($variant),+> {
+            /// This function is part of the generated code from `pin-project-lite`,
+            /// for a more in depth explanation and the rest of the generated code refer
+            /// to the [`proj`] module of [`Either`].
+            ///
+            /// [`proj`]: crate::either::proj
+            /// [`Either`]: crate::Either
+            pub(crate) fn project(self: Pin<&mut Self>) -> $proj_mod::$proj<'_, $($variant),+> {
+                unsafe {
+                    match self.get_unchecked_mut() {
+                        $(
+                            Self::$variant(inner) => {
+                                $proj_mod::$proj::$variant(Pin::new_unchecked(inner))
+                            }
+                        )+
+                    }
+                }
+            }
+        }
+
+        impl<T> $name<$(replace_ty!($variant, T)),+> {
+            #[doc = concat!(
+                "Convert [`", stringify!($name), "`] into the inner type, if all variants are of ",
+                "the same type."
+            )]
+            pub fn into_inner(self) -> T {
+                match self {
+                    $(Self::$variant(inner) => inner,)+
+                }
+            }
+        }
+
+        impl<$($variant),+, Data> Body for $name<$($variant),+>
+        where
+            $(
+                $variant: Body<Data = Data>,
+                <$variant as Body>::Error: Into<Box<dyn Error + Send + Sync>>,
+            )+
+            Data: Buf,
+        {
+            type Data = Data;
+            type Error = Box<dyn Error + Send + Sync>;
+
+            fn poll_frame(
+                self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+            ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+                match self.project() {
+                    $(
+                        $proj_mod::$proj::$variant(inner) => inner
+                            .poll_frame(cx)
+                            .map(|poll| poll.map(|opt| opt.map_err(Into::into))),
+                    )+
+                }
+            }
+
+            fn is_end_stream(&self) -> bool {
+                match self {
+                    $(Self::$variant(inner) => inner.is_end_stream(),)+
+                }
+            }
+
+            fn size_hint(&self) -> SizeHint {
+                match self {
+                    $(Self::$variant(inner) => inner.size_hint(),)+
+                }
+            }
+        }
+
+        pub(crate) mod $proj_mod {
+            //! Projection for the enum of the same name, following the same
+            //! expansion as [`crate::either::proj`].
+            use std::marker::PhantomData;
+            use std::pin::Pin;
+
+            use super::$name;
+
+            #[allow(dead_code)]
+            #[allow(single_use_lifetimes)]
+            #[allow(unknown_lints)]
+            #[allow(clippy::mut_mut)]
+            #[allow(clippy::redundant_pub_crate)]
+            #[allow(clippy::ref_option_ref)]
+            #[allow(clippy::type_repetition_in_bounds)]
+            pub(crate) enum $proj<'__pin, $($variant),+>
+            where
+                $name<$($variant),+>: '__pin,
+            {
+                $($variant(Pin<&'__pin mut $variant>),)+
+            }
+
+            #[allow(single_use_lifetimes)]
+            #[allow(unknown_lints)]
+            #[allow(clippy::used_underscore_binding)]
+            #[allow(missing_debug_implementations)]
+            const _: () = {
+                #[allow(dead_code, non_snake_case)]
+                pub struct __Origin<'__pin, $($variant),+> {
+                    __dummy_lifetime: PhantomData<&'__pin ()>,
+                    $($variant: $variant,)+
+                }
+                impl<'__pin, $($variant),+> Unpin for $name<$($variant),+>
+                where
+                    __Origin<'__pin, $($variant),+>: Unpin
+                {
+                }
+
+                #[allow(dead_code)]
+                trait MustNotImplDrop {}
+                #[allow(drop_bounds)]
+                impl<T: Drop> MustNotImplDrop for T {}
+                impl<$($variant),+> MustNotImplDrop for $name<$($variant),+> {}
+            };
+        }
+    };
+}
+
+either_n! {
+    /// Sum type with three cases, used if a body can be one of three distinct types.
+    Either3, either3::Either3Proj { A, B, C }
+}
+
+either_n! {
+    /// Sum type with four cases, used if a body can be one of four distinct types.
+    Either4, either4::Either4Proj { A, B, C, D }
+}
+
+either_n! {
+    /// Sum type with five cases, used if a body can be one of five distinct types.
+    Either5, either5::Either5Proj { A, B, C, D, E }
+}
+
+either_n! {
+    /// Sum type with six cases, used if a body can be one of six distinct types.
+    Either6, either6::Either6Proj { A, B, C, D, E, F }
+}
+
+either_n! {
+    /// Sum type with seven cases, used if a body can be one of seven distinct types.
+    Either7, either7::Either7Proj { A, B, C, D, E, F, G }
+}
+
+either_n! {
+    /// Sum type with eight cases, used if a body can be one of eight distinct types.
+    Either8, either8::Either8Proj { A, B, C, D, E, F, G, H }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+    use crate::{BodyExt, Empty, Full};
+
+    #[tokio::test]
+    async fn data_middle() {
+        let full = Full::<_, Infallible>::new(&b"hello"[..]);
+
+        type E = Empty<&'static [u8], Infallible>;
+        let mut value: Either3<E, _, E> = Either3::B(full);
+
+        assert_eq!(value.size_hint().exact(), Some(b"hello".len() as u64));
+        assert_eq!(
+            value.frame().await.unwrap().unwrap().into_data().unwrap(),
+            &b"hello"[..]
+        );
+        assert!(value.frame().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn data_last() {
+        let full = Full::<_, Infallible>::new(&b"hello!"[..]);
+
+        type E = Empty<&'static [u8], Infallible>;
+        let mut value: Either8<E, E, E, E, E, E, E, _> = Either8::H(full);
+
+        assert!(!value.is_end_stream());
+        assert_eq!(
+            value.frame().await.unwrap().unwrap().into_data().unwrap(),
+            &b"hello!"[..]
+        );
+        assert!(value.frame().await.is_none());
+        assert!(value.is_end_stream());
+    }
+
+    #[test]
+    fn into_inner() {
+        let a = Either4::<i32, i32, i32, i32>::C(2);
+        assert_eq!(a.into_inner(), 2)
+    }
+}
@@ -1,13 +1,17 @@
 use bytes::Buf;
+use http::HeaderMap;
 use http_body::{Body, Frame, SizeHint};
 use std::{
     convert::Infallible,
     fmt,
+    future::{self, Ready},
     marker::PhantomData,
     pin::Pin,
     task::{Context, Poll},
 };
 
+use crate::combinators::WithTrailers;
+
 /// A body that is always empty.
 pub struct Empty<D, E = Infallible> {
     _marker: PhantomData<fn() -> (D, E)>,
@@ -22,6 +26,17 @@ impl<D, E> Empty<D, E> {
     }
 }
 
+impl<D: Buf, E> Empty<D, E> {
+    /// Create a body with no data that yields a single trailers frame and then ends.
+    ///
+    /// This is useful for trailers-only responses, such as gRPC errors.
+    pub fn with_trailers(
+        trailers: HeaderMap,
+    ) -> WithTrailers<Self, Ready<Option<Result<HeaderMap, E>>>> {
+        WithTrailers::new(Empty::new(), future::ready(Some(Ok(trailers))))
+    }
+}
+
 impl<D: Buf, E> Body for Empty<D, E> {
     type Data = D;
     type Error = E;
@@ -62,3 +77,25 @@ impl<D, E> Clone for Empty<D, E> {
 }
 
 impl<D, E> Copy for Empty<D, E> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyExt;
+    use bytes::Bytes;
+
+    #[tokio::test]
+    async fn empty_with_trailers() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("grpc-status", "0".parse().unwrap());
+
+        let mut body = Empty::<Bytes>::with_trailers(trailers);
+        assert_eq!(body.size_hint().exact(), Some(0));
+        assert!(!body.is_end_stream());
+
+        let frame = body.frame().await.unwrap().unwrap();
+        assert_eq!(frame.into_trailers().unwrap()["grpc-status"], "0");
+        assert!(body.is_end_stream());
+        assert!(body.frame().await.is_none());
+    }
+}
@@ -1,14 +1,23 @@
 use bytes::{Buf, Bytes};
+use http::HeaderMap;
 use http_body::{Body, Frame, SizeHint};
 use pin_project_lite::pin_project;
 use std::borrow::Cow;
 use std::convert::{Infallible, TryFrom};
+use std::future::{self, Ready};
 use std::marker::PhantomData;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+use crate::combinators::WithTrailers;
+
 pin_project! {
     /// A body that consists of a single chunk.
+    ///
+    /// This is the way to use a `Bytes`, `Vec<u8>` or `&'static [u8]` as a body, through
+    /// [`Full::from`] or [`Full::from_static`]. Those types do not implement `Body` themselves,
+    /// because the combinator methods would clash with methods such as `Buf::take` and
+    /// `Write::by_ref`.
     #[derive(Clone, Copy, Debug)]
     pub struct Full<D, E = Infallible> {
         data: Option<D>,
@@ -21,6 +30,9 @@ where
     D: Buf,
 {
     /// Create a new `Full`.
+    ///
+    /// If `data` is empty, no DATA frame is yielded and the body ends immediately, just like
+    /// [`Empty`](crate::Empty).
     pub fn new(data: D) -> Self {
         let data = if data.has_remaining() {
             Some(data)
@@ -33,6 +45,30 @@ where
             _marker: PhantomData,
         }
     }
+
+    /// Create an empty `Full`, which yields no frames.
+    ///
+    /// This is the same as `Full::new` with an empty buffer, or `Full::default()`.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Create a new `Full` that is followed by a trailers frame.
+    ///
+    /// The returned body yields `data` as a single DATA frame, then `trailers`, and then ends.
+    pub fn with_trailers(
+        data: D,
+        trailers: HeaderMap,
+    ) -> WithTrailers<Self, Ready<Option<Result<HeaderMap, E>>>> {
+        WithTrailers::new(Full::new(data), future::ready(Some(Ok(trailers))))
+    }
+}
+
+impl<E> Full<Bytes, E> {
+    /// Create a new `Full` from a static byte slice, without copying it.
+    pub fn from_static(data: &'static [u8]) -> Self {
+        Full::new(Bytes::from_static(data))
+    }
 }
 
 impl<D, E> Body for Full<D, E>
@@ -148,9 +184,60 @@ mod tests {
         assert!(full.frame().await.is_none());
     }
 
+    #[tokio::test]
+    async fn full_from_static() {
+        static DATA: &[u8] = b"hello";
+
+        let mut full = Full::<Bytes, Infallible>::from_static(DATA);
+        assert_eq!(full.size_hint().exact(), Some(5));
+        let data = full.frame().await.unwrap().unwrap().into_data().unwrap();
+        assert_eq!(data.as_ptr(), DATA.as_ptr());
+    }
+
+    #[tokio::test]
+    async fn full_with_trailers() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("foo", "bar".parse().unwrap());
+
+        let mut full = Full::<_, Infallible>::with_trailers(&b"hello"[..], trailers.clone());
+        assert_eq!(full.size_hint().exact(), Some(b"hello".len() as u64));
+        assert!(!full.is_end_stream());
+
+        assert_eq!(
+            full.frame().await.unwrap().unwrap().into_data().unwrap(),
+            &b"hello"[..]
+        );
+        assert!(!full.is_end_stream());
+        assert_eq!(
+            full.frame()
+                .await
+                .unwrap()
+                .unwrap()
+                .into_trailers()
+                .unwrap(),
+            trailers
+        );
+        assert!(full.is_end_stream());
+        assert!(full.frame().await.is_none());
+    }
+
     #[tokio::test]
     async fn empty_full_returns_none() {
         assert!(Full::<&[u8], Infallible>::default().frame().await.is_none());
         assert!(Full::<_, Infallible>::new(&b""[..]).frame().await.is_none());
     }
+
+    #[tokio::test]
+    async fn empty_full_behaves_like_empty() {
+        let bodies = [
+            Full::<Bytes, Infallible>::empty(),
+            Full::new(Bytes::new()),
+            Full::default(),
+        ];
+        for mut full in bodies {
+            assert!(full.is_end_stream());
+            assert_eq!(full.size_hint().exact(), Some(0));
+            assert!(full.frame().await.is_none());
+        }
+    }
 }
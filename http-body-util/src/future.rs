@@ -0,0 +1,102 @@
+use bytes::Buf;
+use futures_core::ready;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// A body created from a [`Future`] that resolves to a single chunk of data.
+    ///
+    /// The future's output is yielded as one DATA frame, after which the body ends. If the
+    /// future fails, its error is the body's error.
+    #[derive(Clone, Copy, Debug)]
+    pub struct FutureBody<F> {
+        #[pin]
+        future: Option<F>,
+    }
+}
+
+impl<F> FutureBody<F> {
+    /// Create a new `FutureBody`.
+    pub fn new(future: F) -> Self {
+        Self {
+            future: Some(future),
+        }
+    }
+}
+
+impl<F, D, E> Body for FutureBody<F>
+where
+    F: Future<Output = Result<D, E>>,
+    D: Buf,
+{
+    type Data = D;
+    type Error = E;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let mut this = self.project();
+
+        let future = match this.future.as_mut().as_pin_mut() {
+            Some(future) => future,
+            None => return Poll::Ready(None),
+        };
+        let res = ready!(future.poll(cx));
+        this.future.set(None);
+
+        match res {
+            Ok(data) if data.has_remaining() => Poll::Ready(Some(Ok(Frame::data(data)))),
+            Ok(_) => Poll::Ready(None),
+            Err(err) => Poll::Ready(Some(Err(err))),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.future.is_none()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        if self.future.is_none() {
+            SizeHint::with_exact(0)
+        } else {
+            SizeHint::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::BodyExt;
+
+    #[tokio::test]
+    async fn yields_future_output() {
+        let mut body = Box::pin(FutureBody::new(async {
+            Ok::<_, Infallible>(Bytes::from("hello"))
+        }));
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hello"
+        );
+        assert!(body.is_end_stream());
+        assert!(body.frame().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn yields_future_error() {
+        let body = FutureBody::new(async { Err::<Bytes, _>("boom") });
+
+        assert_eq!(body.collect().await.unwrap_err(), "boom");
+    }
+}
@@ -10,27 +10,39 @@
 mod collected;
 pub mod combinators;
 mod either;
+mod either_n;
 mod empty;
 mod full;
+mod future;
 mod limited;
+mod repeat;
 mod stream;
 
 #[cfg(feature = "channel")]
 pub mod channel;
 
+#[cfg(feature = "io")]
+mod reader;
+
 mod util;
 
-use self::combinators::{BoxBody, MapErr, MapFrame, UnsyncBoxBody};
+use self::combinators::{BoxBody, Chain, MapErr, MapFrame, UnsyncBoxBody};
 
 pub use self::collected::Collected;
 pub use self::either::Either;
+pub use self::either_n::{Either3, Either4, Either5, Either6, Either7, Either8};
 pub use self::empty::Empty;
 pub use self::full::Full;
-pub use self::limited::{LengthLimitError, Limited};
-pub use self::stream::{BodyDataStream, BodyStream, StreamBody};
+pub use self::future::FutureBody;
+pub use self::limited::{LengthLimitError, Limited, RejectOversize, SplitOversize};
+pub use self::repeat::Repeat;
+pub use self::stream::{BodyBytesStream, BodyDataStream, BodyStream, DataStreamBody, StreamBody};
 
 #[cfg(feature = "channel")]
-pub use self::channel::Channel;
+pub use self::channel::{Channel, ChannelEnd};
+
+#[cfg(feature = "io")]
+pub use self::reader::{BodyReader, ReaderBody};
 
 /// An extension trait for [`http_body::Body`] adding various combinators and adapters
 pub trait BodyExt: http_body::Body {
@@ -44,6 +56,42 @@ pub trait BodyExt: http_body::Body {
         combinators::Frame(self)
     }
 
+    /// Borrows this body, rather than consuming it.
+    ///
+    /// This is useful to apply a combinator to part of a body and then keep using the original
+    /// body afterwards, like [`Iterator::by_ref`]. It relies on `Body` being implemented for
+    /// `&mut B`, which requires `B: Unpin`; pin the body with [`Box::pin`] if it is not.
+    ///
+    /// For types that are also a `Stream`, such as [`BodyStream`], this is ambiguous with
+    /// `StreamExt::by_ref` when both traits are in scope. Call it as `BodyExt::by_ref(&mut body)`
+    /// in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use http_body_util::{BodyExt, Full};
+    /// use std::convert::Infallible;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut body = Full::<_, Infallible>::new(Bytes::from("hello"))
+    ///     .chain(Full::<_, Infallible>::new(Bytes::from(" world")));
+    ///
+    /// let head = body.by_ref().take(5).collect().await.unwrap();
+    /// assert_eq!(head.to_bytes(), "hello");
+    ///
+    /// let rest = body.collect().await.unwrap();
+    /// assert_eq!(rest.to_bytes(), " world");
+    /// # }
+    /// ```
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
     /// Maps this body's frame to a different kind.
     fn map_frame<F, B>(self, f: F) -> MapFrame<Self, F>
     where
@@ -54,6 +102,87 @@ pub trait BodyExt: http_body::Body {
         MapFrame::new(self, f)
     }
 
+    /// Maps this body's frames asynchronously.
+    ///
+    /// Each frame is passed to `f`, and the returned future is driven to completion before the
+    /// next frame is polled, so frames stay in order. Errors from the inner body are converted
+    /// into the future's error type.
+    fn then_frame<F, Fut, B, E>(self, f: F) -> combinators::ThenFrame<Self, F, Fut>
+    where
+        Self: Sized,
+        Self::Error: Into<E>,
+        F: FnMut(http_body::Frame<Self::Data>) -> Fut,
+        Fut: std::future::Future<Output = Result<http_body::Frame<B>, E>>,
+        B: bytes::Buf,
+    {
+        combinators::ThenFrame::new(self, f)
+    }
+
+    /// Maps this body's frames, dropping any for which `f` returns `None`.
+    ///
+    /// Dropped frames are skipped and the inner body is polled again, so the returned body only
+    /// yields the frames that `f` keeps.
+    fn filter_frame<F, B>(self, f: F) -> combinators::FilterFrame<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(http_body::Frame<Self::Data>) -> Option<http_body::Frame<B>>,
+        B: bytes::Buf,
+    {
+        combinators::FilterFrame::new(self, f)
+    }
+
+    /// Calls a closure on each frame of this body, passing it on unchanged.
+    ///
+    /// The closure is given a reference to every successfully polled frame before it is
+    /// returned. Errors are passed through without calling the closure.
+    fn inspect_frame<F>(self, f: F) -> combinators::InspectFrame<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&http_body::Frame<Self::Data>),
+    {
+        combinators::InspectFrame::new(self, f)
+    }
+
+    /// Calls `on_data` on each DATA frame and `on_trailers` on each trailers frame of this
+    /// body, passing the frames on unchanged.
+    ///
+    /// This is like [`BodyExt::inspect_frame`], with a separate closure for each kind of frame.
+    /// Errors are passed through without calling either closure.
+    fn route<F, G>(self, on_data: F, on_trailers: G) -> combinators::Route<Self, F, G>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Data),
+        G: FnMut(&http::HeaderMap),
+    {
+        combinators::Route::new(self, on_data, on_trailers)
+    }
+
+    /// Maps this body's data buffers to a different type.
+    ///
+    /// Trailers frames are passed through unchanged.
+    fn map_data<F, D>(self, f: F) -> combinators::MapData<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Data) -> D,
+        D: bytes::Buf,
+    {
+        combinators::MapData::new(self, f)
+    }
+
+    /// Maps this body's frames with a closure that carries state from one frame to the next.
+    ///
+    /// `f` is called with a mutable reference to the state, which starts as `init`, and each
+    /// frame. Frames for which it returns `None` are skipped, as with
+    /// [`BodyExt::filter_frame`], which makes this suitable for re-framing data.
+    fn scan_frames<S, F, B>(self, init: S, f: F) -> combinators::ScanFrames<Self, S, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut S, http_body::Frame<Self::Data>) -> Option<http_body::Frame<B>>,
+        B: bytes::Buf,
+    {
+        combinators::ScanFrames::new(self, init, f)
+    }
+
     /// Maps this body's error value to a different value.
     fn map_err<F, E>(self, f: F) -> MapErr<Self, F>
     where
@@ -63,7 +192,89 @@ pub trait BodyExt: http_body::Body {
         MapErr::new(self, f)
     }
 
+    /// Converts this body's error value into `E` with [`Into`].
+    ///
+    /// This is equivalent to `.map_err(Into::into)`, but names the target error type rather
+    /// than a closure.
+    fn err_into<E>(self) -> combinators::ErrInto<Self, E>
+    where
+        Self: Sized,
+        Self::Error: Into<E>,
+    {
+        combinators::ErrInto::new(self)
+    }
+
+    /// Labels this body's errors with `label`.
+    ///
+    /// Errors are wrapped in a [`ContextError`](combinators::ContextError), which displays as
+    /// `body error in <label>: <cause>` and returns the original error from
+    /// [`Error::source`](std::error::Error::source). This makes it possible to tell which body
+    /// in a stack of combinators produced an error. Frames are passed on unchanged.
+    fn context(self, label: &'static str) -> combinators::WithContext<Self>
+    where
+        Self: Sized,
+        Self::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        combinators::WithContext::new(self, label)
+    }
+
+    /// Maps both this body's frames and its error value, in a single combinator.
+    ///
+    /// This behaves like [`BodyExt::map_frame`] followed by [`BodyExt::map_err`], without
+    /// nesting two bodies.
+    fn map_both<F, G, B, E>(self, f_frame: F, f_err: G) -> combinators::MapBoth<Self, F, G>
+    where
+        Self: Sized,
+        F: FnMut(http_body::Frame<Self::Data>) -> http_body::Frame<B>,
+        G: FnMut(Self::Error) -> E,
+        B: bytes::Buf,
+    {
+        combinators::MapBoth::new(self, f_frame, f_err)
+    }
+
+    /// Maps this body's error into a boxed trait object.
+    ///
+    /// This is a shorthand for `.map_err(Into::into)`, which is commonly needed to unify the
+    /// error types of different bodies.
+    #[allow(clippy::type_complexity)]
+    fn map_err_boxed(
+        self,
+    ) -> MapErr<Self, fn(Self::Error) -> Box<dyn std::error::Error + Send + Sync>>
+    where
+        Self: Sized,
+        Self::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        MapErr::new(self, Into::into)
+    }
+
+    /// Chain this body with another one.
+    ///
+    /// The returned body yields every frame of `self`, including any trailers, and then every
+    /// frame of `other`. Errors from both bodies are converted into
+    /// `Box<dyn Error + Send + Sync>`, like [`Either`] does.
+    fn chain<B>(self, other: B) -> Chain<Self, B>
+    where
+        Self: Sized,
+        B: http_body::Body<Data = Self::Data>,
+    {
+        Chain::new(self, other)
+    }
+
+    /// Yield `data` as a DATA frame before the frames of this body.
+    ///
+    /// This is like chaining a [`Full`] body in front of this one, without having to unify
+    /// their error types.
+    fn prepend_data(self, data: Self::Data) -> combinators::Prepend<Self, Self::Data>
+    where
+        Self: Sized,
+    {
+        combinators::Prepend::new(self, data)
+    }
+
     /// Turn this body into a boxed trait object.
+    ///
+    /// The body must be `Send + Sync`; use [`BodyExt::boxed_unsync`] for bodies that are not
+    /// `Sync`.
     fn boxed(self) -> BoxBody<Self::Data, Self::Error>
     where
         Self: Sized + Send + Sync + 'static,
@@ -79,6 +290,31 @@ pub trait BodyExt: http_body::Body {
         UnsyncBoxBody::new(self)
     }
 
+    /// Turn this body into a boxed trait object that is neither `Send` nor `Sync`.
+    ///
+    /// Unlike [`BodyExt::boxed_unsync`], the body need not be `Send`, but the resulting
+    /// [`LocalBoxBody`] cannot be sent to another thread.
+    ///
+    /// [`LocalBoxBody`]: combinators::LocalBoxBody
+    fn boxed_local(self) -> combinators::LocalBoxBody<Self::Data, Self::Error>
+    where
+        Self: Sized + 'static,
+    {
+        combinators::LocalBoxBody::new(self)
+    }
+
+    /// Turn this body into a boxed trait object that can be cloned.
+    ///
+    /// The body must be `Clone + Send + Sync`. See [`CloneBoxBody`] for how clones behave.
+    ///
+    /// [`CloneBoxBody`]: combinators::CloneBoxBody
+    fn boxed_clone(self) -> combinators::CloneBoxBody<Self::Data, Self::Error>
+    where
+        Self: Sized + Clone + Send + Sync + 'static,
+    {
+        combinators::CloneBoxBody::new(self)
+    }
+
     /// Turn this body into [`Collected`] body which will collect all the DATA frames
     /// and trailers.
     fn collect(self) -> combinators::Collect<Self>
@@ -91,11 +327,89 @@ pub trait BodyExt: http_body::Body {
         }
     }
 
+    /// Append all the DATA frames of this body to `buf`, resolving to its trailers.
+    ///
+    /// Unlike [`BodyExt::collect`], this does not allocate a new buffer for the data, so a single
+    /// buffer can be reused across many bodies. Data already in `buf` is left untouched. If the
+    /// body yields more than one trailers frame, they are merged.
+    fn collect_into(self, buf: &mut bytes::BytesMut) -> combinators::CollectInto<'_, Self>
+    where
+        Self: Sized,
+    {
+        combinators::CollectInto {
+            body: self,
+            buf,
+            trailers: None,
+        }
+    }
+
+    /// Turn this body into [`Collected`] body which will collect all the DATA frames
+    /// and trailers, failing if the body yields more than `limit` bytes of data.
+    ///
+    /// The limit is checked before each DATA frame is buffered, so a body that is too large is
+    /// rejected without holding on to the offending frame. On failure the future resolves to a
+    /// [`CollectLimitError`](combinators::CollectLimitError).
+    fn collect_limited(self, limit: usize) -> combinators::CollectLimited<Self>
+    where
+        Self: Sized,
+    {
+        self.collect_with_opts(combinators::CollectOpts::new().max_data(limit))
+    }
+
+    /// Turn this body into [`Collected`] body which will collect all the DATA frames
+    /// and trailers, subject to the limits set in `opts`.
+    ///
+    /// Besides the data limit of [`BodyExt::collect_limited`], this can bound the total size of
+    /// the trailers, which would otherwise be unlimited. On failure the future resolves to a
+    /// [`CollectLimitError`](combinators::CollectLimitError) or a
+    /// [`TrailersLimitError`](combinators::TrailersLimitError).
+    fn collect_with_opts(self, opts: combinators::CollectOpts) -> combinators::CollectLimited<Self>
+    where
+        Self: Sized,
+    {
+        let limit = opts.max_data.unwrap_or(usize::MAX);
+        let trailers_limit = opts.max_trailers_bytes.unwrap_or(usize::MAX);
+        combinators::CollectLimited {
+            body: self,
+            remaining: limit,
+            limit,
+            trailers_remaining: trailers_limit,
+            trailers_limit,
+            collected: Some(crate::Collected::default()),
+        }
+    }
+
+    /// Drive this body to completion, discarding its data, and resolve to its trailers.
+    ///
+    /// Data frames are dropped as soon as they are received, so memory use does not depend on
+    /// the size of the body. If the body yields more than one trailers frame, they are merged.
+    ///
+    /// This is useful when only the trailers of a response matter, such as the status of a
+    /// gRPC call.
+    #[doc(alias = "trailers")]
+    fn drain_trailers(self) -> combinators::DrainTrailers<Self>
+    where
+        Self: Sized,
+    {
+        combinators::DrainTrailers {
+            body: self,
+            trailers: None,
+        }
+    }
+
     /// Add trailers to the body.
     ///
     /// The trailers will be sent when all previous frames have been sent and the `trailers` future
     /// resolves.
     ///
+    /// If the body yields its own trailers frame, the trailers produced by the future are merged
+    /// into it and a single trailers frame is sent. Entries from the future replace entries of
+    /// the same name sent by the body. The size hint is that of the wrapped body, since trailers
+    /// carry no data.
+    ///
+    /// Use [`WithTrailers::trailer_merge`](combinators::WithTrailers::trailer_merge) to replace
+    /// the body's trailers instead, or to yield both as separate frames.
+    ///
     /// # Example
     ///
     /// ```
@@ -127,6 +441,27 @@ pub trait BodyExt: http_body::Body {
     /// }
     /// # }
     /// ```
+    ///
+    /// Trailers that are already known can be appended with an already-completed future:
+    ///
+    /// ```
+    /// use http::HeaderMap;
+    /// use http_body_util::{Full, BodyExt};
+    /// use bytes::Bytes;
+    /// use std::convert::Infallible;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut trailers = HeaderMap::new();
+    /// trailers.insert("grpc-status", "0".parse().unwrap());
+    ///
+    /// let body = Full::<Bytes>::from("Hello, World!")
+    ///     .with_trailers(std::future::ready(Some(Ok::<_, Infallible>(trailers))));
+    ///
+    /// let collected = body.collect().await.unwrap();
+    /// assert_eq!(collected.trailers().unwrap()["grpc-status"], "0");
+    /// # }
+    /// ```
     fn with_trailers<F>(self, trailers: F) -> combinators::WithTrailers<Self, F>
     where
         Self: Sized,
@@ -135,13 +470,500 @@ pub trait BodyExt: http_body::Body {
         combinators::WithTrailers::new(self, trailers)
     }
 
+    /// Add trailers that are computed once the body has ended.
+    ///
+    /// `f` is called after all previous frames have been sent, and the future it returns is
+    /// polled to completion before the trailers are sent. If the future resolves to `None`, no
+    /// trailers frame is sent. Trailers yielded by the body itself are merged in the same way as
+    /// [`BodyExt::with_trailers`].
+    ///
+    /// This allows trailers to depend on the streamed content, such as a checksum or the total
+    /// number of bytes.
+    fn with_trailers_fn<F, Fut>(self, f: F) -> combinators::WithTrailersFn<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Option<http::HeaderMap>>,
+    {
+        combinators::WithTrailersFn::new(self, f)
+    }
+
+    /// Split this body into a body that yields only its DATA frames, and a future that
+    /// resolves to its trailers.
+    ///
+    /// The trailers future resolves once the data body has reached the end of the body, so the
+    /// data body must be driven for it to complete. If the data body is dropped first, the
+    /// future resolves to the trailers seen so far, or `None` if there were none.
+    fn split_frames(self) -> (combinators::DataFrames<Self>, combinators::Trailers)
+    where
+        Self: Sized,
+    {
+        combinators::DataFrames::new(self)
+    }
+
     /// Turn this body into [`BodyDataStream`].
+    ///
+    /// The returned stream only yields data, and any trailers are discarded. Use
+    /// [`BodyExt::into_data_stream_with_trailers`] to keep them.
     fn into_data_stream(self) -> BodyDataStream<Self>
     where
         Self: Sized,
     {
         BodyDataStream::new(self)
     }
+
+    /// Turn this body into a [`BodyBytesStream`].
+    ///
+    /// This is like [`BodyExt::into_data_stream`], but each data buffer is converted into
+    /// [`Bytes`](bytes::Bytes), which suits APIs that expect a
+    /// `Stream<Item = Result<Bytes, E>>`. Any trailers are discarded.
+    fn into_bytes_stream(self) -> BodyBytesStream<Self>
+    where
+        Self: Sized,
+    {
+        BodyBytesStream::new(self)
+    }
+
+    /// Turn this body into a [`BodyDataStream`] and a future that resolves to the body's
+    /// trailers.
+    ///
+    /// The trailers future resolves once the stream has reached the end of the body, so the
+    /// stream must be driven for it to complete.
+    fn into_data_stream_with_trailers(
+        self,
+    ) -> (
+        BodyDataStream<combinators::DataFrames<Self>>,
+        combinators::Trailers,
+    )
+    where
+        Self: Sized,
+    {
+        let (body, trailers) = combinators::DataFrames::new(self);
+        (BodyDataStream::new(body), trailers)
+    }
+
+    /// Limit the number of frames this body may yield.
+    ///
+    /// Every frame counts towards the limit, whether it contains data or trailers. Once more
+    /// than `max_frames` frames have been polled, the returned body yields a
+    /// [`FrameLimitError`](combinators::FrameLimitError).
+    fn limit_frames(self, max_frames: usize) -> combinators::FrameLimited<Self>
+    where
+        Self: Sized,
+    {
+        combinators::FrameLimited::new(self, max_frames)
+    }
+
+    /// Check that this body yields exactly `expected` bytes of data.
+    ///
+    /// This is useful to enforce a declared `Content-Length`. If the body yields more data
+    /// than expected, the returned body yields a
+    /// [`LengthMismatch`](combinators::LengthMismatch) error as soon as the data frame that
+    /// exceeds it is polled. If the body ends with less data, the error is yielded at the end
+    /// instead, so truncated bodies are caught as well. Unlike [`Limited`], which only caps the
+    /// length, both cases are errors.
+    fn verify_length(self, expected: u64) -> combinators::VerifyLength<Self>
+    where
+        Self: Sized,
+    {
+        combinators::VerifyLength::new(self, expected)
+    }
+
+    /// Copies the bytes of each DATA frame into `sink` as the body is polled.
+    ///
+    /// Frames are passed on unchanged, and trailers and errors are not given to `sink`.
+    ///
+    /// `sink` is called inline from `poll_frame`, so a slow sink slows down the body as well.
+    /// To decouple the two, `sink` can push into a bounded channel using a non-blocking send,
+    /// dropping the copy when the channel is full.
+    fn tee<F>(self, sink: F) -> combinators::Tee<Self, F>
+    where
+        Self: Sized,
+        Self::Data: AsRef<[u8]>,
+        F: FnMut(&[u8]),
+    {
+        combinators::Tee::new(self, sink)
+    }
+
+    /// Record the frames of this body as it is polled.
+    ///
+    /// Returns the wrapped body, which yields frames unchanged, along with a
+    /// [`Recording`](combinators::Recording) future that resolves to the recorded data and
+    /// trailers once the body has reached its end. This is useful for write-through caching,
+    /// where a body is streamed to a client and then stored.
+    ///
+    /// Each data frame is cloned as it passes through, which is cheap for [`Bytes`]. The body's
+    /// error is passed on to the reader of the body, so the future only reports that the body
+    /// errored, or was dropped, with a [`RecordError`](combinators::RecordError).
+    ///
+    /// [`Bytes`]: bytes::Bytes
+    fn record(
+        self,
+    ) -> (
+        combinators::RecordingBody<Self>,
+        combinators::Recording<Self::Data>,
+    )
+    where
+        Self: Sized,
+        Self::Data: Clone,
+    {
+        combinators::RecordingBody::new(self)
+    }
+
+    /// Coalesce consecutive data frames until at least `min_chunk` bytes are buffered.
+    ///
+    /// Buffered data is yielded early when the inner body ends or yields a trailers frame, in
+    /// which case the data is yielded before the trailers. Data frames that reach `min_chunk`
+    /// on their own are passed through without copying when nothing is buffered.
+    ///
+    /// Buffered data is only yielded once the threshold is met, so a slow body may have data
+    /// held back for a while.
+    fn buffer(self, min_chunk: usize) -> combinators::Buffered<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Buffered::new(self, min_chunk)
+    }
+
+    /// Yield at most `n` bytes of data from this body, then end.
+    ///
+    /// The data frame that crosses the boundary is truncated, and the rest of the body,
+    /// including any trailers, is never polled. Unlike [`Limited`], reaching `n` is not an
+    /// error.
+    fn take(self, n: usize) -> combinators::Take<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Take::new(self, n)
+    }
+
+    /// Discard the first `n` bytes of data from this body.
+    ///
+    /// The data frame that crosses the boundary is advanced past the skipped bytes and the
+    /// remainder is yielded. Trailers are always passed through.
+    fn skip(self, n: usize) -> combinators::Skip<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Skip::new(self, n)
+    }
+
+    /// Turn this body into a [`Peekable`](combinators::Peekable) body, which allows looking at
+    /// the next frame without consuming it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn doc() {
+    /// use bytes::Bytes;
+    /// use http_body_util::{BodyExt, Full};
+    ///
+    /// let mut body = Full::<_, std::convert::Infallible>::new(Bytes::from("hello")).peekable();
+    ///
+    /// let first = body.peek().await.unwrap().as_ref().unwrap();
+    /// assert!(first.data_ref().unwrap().starts_with(b"he"));
+    ///
+    /// // The peeked frame is still delivered.
+    /// assert_eq!(body.collect().await.unwrap().to_bytes(), "hello");
+    /// # }
+    /// ```
+    fn peekable(self) -> combinators::Peekable<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Peekable::new(self)
+    }
+
+    /// Turn this body into a [`BodyReader`], which implements Tokio's [`AsyncRead`].
+    ///
+    /// Reads are filled from the body's data frames, and trailers are discarded. Errors from
+    /// the body are returned as [`std::io::Error`]s.
+    ///
+    /// [`AsyncRead`]: tokio::io::AsyncRead
+    #[cfg(feature = "io")]
+    fn into_async_read(self) -> BodyReader<Self>
+    where
+        Self: Sized,
+    {
+        BodyReader::new(self)
+    }
+
+    /// Count the data bytes yielded by this body.
+    ///
+    /// Returns the wrapped body along with a shared counter, which is incremented as each data
+    /// frame passes through. The counter remains readable after the body is dropped, so it also
+    /// reflects bodies that were not read to completion. Frames are not altered.
+    fn count_bytes(
+        self,
+    ) -> (
+        combinators::CountingBody<Self>,
+        std::sync::Arc<std::sync::atomic::AtomicU64>,
+    )
+    where
+        Self: Sized,
+    {
+        combinators::CountingBody::new(self)
+    }
+
+    /// Measure the throughput of this body.
+    ///
+    /// Returns the wrapped body along with a [`Meter`], which records the number of data bytes
+    /// and the time of the most recent data frame as frames pass through. Frames are not
+    /// altered.
+    ///
+    /// [`Meter`]: combinators::Meter
+    fn meter(self) -> (combinators::MeteredBody<Self>, combinators::Meter)
+    where
+        Self: Sized,
+    {
+        combinators::MeteredBody::new(self)
+    }
+
+    /// Report the progress of this body as data passes through it.
+    ///
+    /// `f` is called with the cumulative number of data bytes after each data frame, and one
+    /// final time when the body ends. The reported total is the body's exact
+    /// [`size_hint`](http_body::Body::size_hint) at the time this combinator was created, if any.
+    /// Frames are not altered.
+    fn progress<F>(self, f: F) -> combinators::OnProgress<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(combinators::Progress),
+    {
+        combinators::OnProgress::new(self, f)
+    }
+
+    /// Drop any trailers yielded by this body, passing data frames through unchanged.
+    fn strip_trailers(self) -> combinators::StripTrailers<Self>
+    where
+        Self: Sized,
+    {
+        combinators::StripTrailers::new(self)
+    }
+
+    /// Re-frame this body's data into length-delimited records.
+    ///
+    /// Each record is a 4-byte big-endian length followed by that many bytes of payload. Data
+    /// is buffered across frames, and exactly one DATA frame is yielded for each complete
+    /// record, without its length prefix. If the body ends, or yields trailers, in the middle of
+    /// a record, an [`IncompleteRecordError`](combinators::IncompleteRecordError) is returned.
+    ///
+    /// Records are buffered in full, so a record whose prefix exceeds 8 MiB is rejected with a
+    /// [`RecordTooLongError`](combinators::RecordTooLongError) before its payload is read. Use
+    /// [`LengthDelimited::max_frame_length`](combinators::LengthDelimited::max_frame_length) to
+    /// change the limit.
+    fn length_delimited(self) -> combinators::LengthDelimited<Self>
+    where
+        Self: Sized,
+    {
+        combinators::LengthDelimited::new(self)
+    }
+
+    /// Record the data of this body as it is yielded, so that it can be rewound and replayed.
+    ///
+    /// Up to `max_buffer` bytes of data are kept, along with any trailers. Calling
+    /// [`Replayable::rewind`] restarts the body from its first frame. Once the body yields more
+    /// data than fits in the buffer, recording stops and rewinding fails.
+    ///
+    /// [`Replayable::rewind`]: combinators::Replayable::rewind
+    fn replayable(self, max_buffer: usize) -> combinators::Replayable<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Replayable::new(self, max_buffer)
+    }
+
+    /// Ensure this body is never polled again once it has finished.
+    ///
+    /// After the inner body yields `None` or an error, every further call to `poll_frame`
+    /// returns `None` without polling it, and `is_end_stream` returns `true`.
+    fn fuse(self) -> combinators::Fuse<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Fuse::new(self)
+    }
+
+    /// Turn this body into a stream of its frames, each paired with its index.
+    ///
+    /// The index starts at zero and counts every frame, including trailers.
+    fn enumerate_frames(self) -> combinators::EnumerateFrames<Self>
+    where
+        Self: Sized,
+    {
+        combinators::EnumerateFrames::new(self)
+    }
+
+    /// Call `f` once when this body finishes.
+    ///
+    /// The [`EndReason`](combinators::EndReason) passed to `f` tells whether the body yielded
+    /// all of its frames, yielded an error, or was dropped before either happened. This makes
+    /// it possible to release resources tied to the lifetime of a body, even if it is never
+    /// read to the end.
+    fn on_end<F>(self, f: F) -> combinators::OnEnd<Self, F>
+    where
+        Self: Sized,
+        F: FnOnce(combinators::EndReason<'_, Self::Error>),
+    {
+        combinators::OnEnd::new(self, f)
+    }
+
+    /// Turn a panic while polling this body into an error.
+    ///
+    /// Each call to `poll_frame` on the inner body is run inside [`std::panic::catch_unwind`].
+    /// If it panics, a [`BodyPanicked`](combinators::BodyPanicked) error is yielded, carrying
+    /// the panic message when there is one, and the inner body is not polled again.
+    ///
+    /// The inner body is treated as [`UnwindSafe`](std::panic::UnwindSafe) without requiring
+    /// it, as it is never observed after a panic except through [`CatchUnwind::get_ref`] and
+    /// similar accessors. A body left in an inconsistent state by a panic should not be reused
+    /// through them. Panics can only be caught if the program is built with `panic = "unwind"`,
+    /// which is the default.
+    ///
+    /// [`CatchUnwind::get_ref`]: combinators::CatchUnwind::get_ref
+    fn catch_unwind(self) -> combinators::CatchUnwind<Self>
+    where
+        Self: Sized,
+    {
+        combinators::CatchUnwind::new(self)
+    }
+
+    /// Merge all the DATA frames of this body into a single frame.
+    ///
+    /// Nothing is yielded until the inner body has finished. Then the concatenated data is
+    /// yielded as one DATA frame, followed by the trailers, if any. Unlike
+    /// [`BodyExt::collect`], the result is still a body.
+    ///
+    /// The whole body is held in memory before the first frame is yielded, so this should only
+    /// be used with bodies of bounded size, for example after [`Limited`].
+    fn coalesce(self) -> combinators::Coalesce<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Coalesce::new(self)
+    }
+
+    /// Panic if this body does not respect the size hint it reports before being polled.
+    ///
+    /// The size hint is captured when this is called. Polling then panics if the body yields
+    /// more data than the hint's upper bound, or ends having yielded less than its lower bound.
+    /// This is meant for testing `Body` implementations.
+    fn assert_size_hint(self) -> combinators::AssertSizeHint<Self>
+    where
+        Self: Sized,
+    {
+        combinators::AssertSizeHint::new(self)
+    }
+
+    /// Yield to the executor after every `every` frames of this body.
+    ///
+    /// A body that is always ready, such as one backed by an in-memory buffer, can otherwise
+    /// keep a task busy for as long as it yields frames. Every `every` frames, this returns
+    /// `Poll::Pending` once, after waking the task so that it is polled again soon.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `every` is zero.
+    fn yield_now_between_frames(self, every: usize) -> combinators::Pace<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Pace::new(self, every)
+    }
+
+    /// Yield `sep` as a DATA frame between each pair of DATA frames of this body.
+    ///
+    /// No separator is yielded before the first frame or after the last one, and empty DATA
+    /// frames are skipped. Trailers are passed through. Since the number of frames is usually
+    /// unknown, the size hint has no upper bound unless `sep` is empty.
+    fn intersperse(self, sep: bytes::Bytes) -> combinators::Intersperse<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Intersperse::new(self, sep)
+    }
+
+    /// Fail this body if it does not yield a frame within `duration`.
+    ///
+    /// The timer is restarted every time a frame is yielded, so this limits how long the body
+    /// may be inactive rather than how long it takes in total. When the timer fires, the returned
+    /// body yields a [`BodyTimeoutError`](combinators::BodyTimeoutError).
+    ///
+    /// This requires a Tokio runtime with the time driver enabled.
+    #[cfg(feature = "time")]
+    fn timeout(self, duration: std::time::Duration) -> combinators::Timeout<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Timeout::new(self, duration)
+    }
+
+    /// Fail this body if it is still being read at the instant `at`.
+    ///
+    /// Unlike [`timeout`](BodyExt::timeout), the deadline is not reset when frames arrive, so
+    /// this caps the total time spent reading the body. Once the deadline has passed, every
+    /// call to `poll_frame` yields a [`DeadlineExceeded`](combinators::DeadlineExceeded) error.
+    /// A body that finishes before the deadline is unaffected.
+    ///
+    /// This requires a Tokio runtime with the time driver enabled.
+    #[cfg(feature = "time")]
+    fn deadline(self, at: tokio::time::Instant) -> combinators::Deadline<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Deadline::new(self, at)
+    }
+
+    /// Limit the rate at which this body yields data to `bytes_per_sec`.
+    ///
+    /// Emitted data is tracked over a sliding one-second window, so no more than `bytes_per_sec`
+    /// bytes are yielded in any one second. Data frames larger than the remaining allowance
+    /// are split, and the rest is held back until earlier data leaves the window. Trailers
+    /// frames are passed through without delay. Only the timing of delivery changes; the
+    /// body's `size_hint` is unaffected.
+    ///
+    /// This requires a Tokio runtime with the time driver enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes_per_sec` is zero.
+    #[cfg(feature = "time")]
+    fn throttle(self, bytes_per_sec: usize) -> combinators::Throttle<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Throttle::new(self, bytes_per_sec)
+    }
+
+    /// Wait for `duration` before yielding the first frame of this body.
+    ///
+    /// The timer starts on the first poll. Once it has elapsed, the inner body is polled as
+    /// usual and frames are passed through without further delay.
+    ///
+    /// This requires a Tokio runtime with the time driver enabled.
+    #[cfg(feature = "time")]
+    fn delay(self, duration: std::time::Duration) -> combinators::Delay<Self>
+    where
+        Self: Sized,
+    {
+        combinators::Delay::new(self, duration)
+    }
+
+    /// Coalesce the DATA frames that arrive within `window` into a single frame.
+    ///
+    /// The window starts when a data frame is buffered and nothing else is. Once it elapses,
+    /// the buffered data is yielded as one frame. Buffered data is also yielded early when the
+    /// inner body ends or yields a trailers frame, in which case the data is yielded before the
+    /// trailers. The total size of the body is unchanged, only its framing.
+    ///
+    /// This requires a Tokio runtime with the time driver enabled.
+    #[cfg(feature = "time")]
+    fn coalesce_for(self, window: std::time::Duration) -> combinators::CoalesceFor<Self>
+    where
+        Self: Sized,
+    {
+        combinators::CoalesceFor::new(self, window)
+    }
 }
 
 impl<T: ?Sized> BodyExt for T where T: http_body::Body {}
@@ -1,8 +1,10 @@
-use bytes::Buf;
+use bytes::{Buf, Bytes};
 use http_body::{Body, Frame, SizeHint};
 use pin_project_lite::pin_project;
+use std::any::type_name;
 use std::error::Error;
 use std::fmt;
+use std::marker::PhantomData;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
@@ -11,28 +13,161 @@ pin_project! {
     ///
     /// This body will return an error if more than the configured number
     /// of bytes are returned on polling the wrapped body.
-    #[derive(Clone, Copy, Debug)]
-    pub struct Limited<B> {
+    ///
+    /// By default the error is a [`LengthLimitError`]. Use [`Limited::with_error`] to
+    /// return a different error instead.
+    ///
+    /// By default a frame that would exceed the limit is rejected as a whole. Use
+    /// [`Limited::with_split`] or [`Limited::split`] to keep the part of it that fits.
+    #[derive(Clone, Copy)]
+    pub struct Limited<B, F = fn(usize) -> LengthLimitError, P = RejectOversize> {
+        limit: usize,
         remaining: usize,
+        exceeded: bool,
+        done: bool,
+        make_err: F,
+        _policy: PhantomData<fn() -> P>,
         #[pin]
         inner: B,
     }
 }
 
+/// Policy for a [`Limited`] body that rejects a frame exceeding the limit as a whole.
+///
+/// This is the default, and keeps the inner body's data type.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RejectOversize;
+
+/// Policy for a [`Limited`] body that truncates the frame which crosses the limit.
+///
+/// The bytes of that frame which still fit are yielded, and the limit error follows on the
+/// next poll, after which the body ends. No data up to the limit is lost, at the cost of
+/// yielding [`Bytes`] rather than the inner body's data type.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SplitOversize;
+
 impl<B> Limited<B> {
     /// Create a new `Limited`.
     pub fn new(inner: B, limit: usize) -> Self {
         Self {
+            limit,
             remaining: limit,
+            exceeded: false,
+            done: false,
+            make_err: |_| LengthLimitError,
+            _policy: PhantomData,
             inner,
         }
     }
 }
 
-impl<B> Body for Limited<B>
+impl<B> Limited<B, fn(usize) -> LengthLimitError, SplitOversize> {
+    /// Create a new `Limited` that truncates the frame which crosses the limit.
+    ///
+    /// See [`SplitOversize`] for details.
+    pub fn with_split(inner: B, limit: usize) -> Self {
+        Limited::new(inner, limit).split()
+    }
+}
+
+impl<B, F> Limited<B, F> {
+    /// Create a new `Limited` that returns a custom error when the limit is exceeded.
+    ///
+    /// `make_err` is called with the configured limit, and the error it returns is yielded
+    /// from `poll_frame` in place of [`LengthLimitError`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use http_body_util::{BodyExt, Full, Limited};
+    ///
+    /// #[derive(Debug)]
+    /// struct PayloadTooLarge(usize);
+    ///
+    /// impl std::fmt::Display for PayloadTooLarge {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         write!(f, "payload larger than {} bytes", self.0)
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for PayloadTooLarge {}
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let body = Full::<Bytes>::from("hello world");
+    /// let limited = Limited::with_error(body, 5, PayloadTooLarge);
+    ///
+    /// let err = limited.collect().await.unwrap_err();
+    /// assert!(err.downcast_ref::<PayloadTooLarge>().is_some());
+    /// # }
+    /// ```
+    pub fn with_error<E>(inner: B, limit: usize, make_err: F) -> Self
+    where
+        F: Fn(usize) -> E,
+    {
+        Self {
+            limit,
+            remaining: limit,
+            exceeded: false,
+            done: false,
+            make_err,
+            _policy: PhantomData,
+            inner,
+        }
+    }
+
+    /// Truncate the frame which crosses the limit, instead of rejecting it as a whole.
+    ///
+    /// See [`SplitOversize`] for details.
+    pub fn split(self) -> Limited<B, F, SplitOversize> {
+        Limited {
+            limit: self.limit,
+            remaining: self.remaining,
+            exceeded: self.exceeded,
+            done: self.done,
+            make_err: self.make_err,
+            _policy: PhantomData,
+            inner: self.inner,
+        }
+    }
+}
+
+impl<B, F, P> Limited<B, F, P> {
+    /// Returns the number of bytes this body was configured to allow.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the number of bytes that may still be read before the limit is exceeded.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Set the number of bytes that may still be read before the limit is exceeded.
+    ///
+    /// The bytes already read are not counted against the new budget, so this can both raise
+    /// and lower the limit. Setting it to zero makes the next non-empty DATA frame fail. The
+    /// value reported by [`Limited::limit`] becomes the bytes read so far plus `remaining`.
+    pub fn set_remaining(&mut self, remaining: usize) {
+        let consumed = self.limit - self.remaining;
+        self.limit = consumed.saturating_add(remaining);
+        self.remaining = remaining;
+    }
+
+    /// Allow `n` more bytes to be read, on top of the current budget.
+    pub fn add_allowance(&mut self, n: usize) {
+        self.limit = self.limit.saturating_add(n);
+        self.remaining = self.remaining.saturating_add(n);
+    }
+}
+
+impl<B, F, E> Body for Limited<B, F, RejectOversize>
 where
     B: Body,
     B::Error: Into<Box<dyn Error + Send + Sync>>,
+    F: Fn(usize) -> E,
+    E: Into<Box<dyn Error + Send + Sync>>,
 {
     type Data = B::Data;
     type Error = Box<dyn Error + Send + Sync>;
@@ -49,7 +184,7 @@ where
                 if let Some(data) = frame.data_ref() {
                     if data.remaining() > *this.remaining {
                         *this.remaining = 0;
-                        Some(Err(LengthLimitError.into()))
+                        Some(Err(limit_error(this.make_err, *this.limit)))
                     } else {
                         *this.remaining -= data.remaining();
                         Some(Ok(frame))
@@ -69,21 +204,98 @@ where
     }
 
     fn size_hint(&self) -> SizeHint {
-        use std::convert::TryFrom;
-        match u64::try_from(self.remaining) {
-            Ok(n) => {
-                let mut hint = self.inner.size_hint();
-                if hint.lower() >= n {
-                    hint.set_exact(n)
-                } else if let Some(max) = hint.upper() {
-                    hint.set_upper(n.min(max))
-                } else {
-                    hint.set_upper(n)
-                }
-                hint
+        limited_size_hint(self.remaining, self.inner.size_hint())
+    }
+}
+
+impl<B, F, E> Body for Limited<B, F, SplitOversize>
+where
+    B: Body,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+    F: Fn(usize) -> E,
+    E: Into<Box<dyn Error + Send + Sync>>,
+{
+    type Data = Bytes;
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        if *this.exceeded {
+            *this.done = true;
+            return Poll::Ready(Some(Err(limit_error(this.make_err, *this.limit))));
+        }
+
+        let frame = match this.inner.poll_frame(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Ready(Some(Ok(frame))) => frame,
+            Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err.into()))),
+        };
+        let mut data = match frame.into_data() {
+            Ok(data) => data,
+            Err(frame) => {
+                let frame = frame.map_data(|mut data| data.copy_to_bytes(data.remaining()));
+                return Poll::Ready(Some(Ok(frame)));
+            }
+        };
+
+        let len = data.remaining();
+        if len > *this.remaining {
+            if *this.remaining == 0 {
+                *this.done = true;
+                return Poll::Ready(Some(Err(limit_error(this.make_err, *this.limit))));
             }
-            Err(_) => self.inner.size_hint(),
+            *this.exceeded = true;
         }
+        let data = data.copy_to_bytes(len.min(*this.remaining));
+        *this.remaining -= data.len();
+        Poll::Ready(Some(Ok(Frame::data(data))))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.done || (!self.exceeded && self.inner.is_end_stream())
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        limited_size_hint(self.remaining, self.inner.size_hint())
+    }
+}
+
+fn limit_error<F, E>(make_err: &F, limit: usize) -> Box<dyn Error + Send + Sync>
+where
+    F: Fn(usize) -> E,
+    E: Into<Box<dyn Error + Send + Sync>>,
+{
+    make_err(limit).into()
+}
+
+fn limited_size_hint(remaining: usize, hint: SizeHint) -> SizeHint {
+    use std::convert::TryFrom;
+    match u64::try_from(remaining) {
+        Ok(n) => hint.clamp_upper(n),
+        Err(_) => hint,
+    }
+}
+
+impl<B, F, P> fmt::Debug for Limited<B, F, P>
+where
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Limited")
+            .field("limit", &self.limit)
+            .field("remaining", &self.remaining)
+            .field("exceeded", &self.exceeded)
+            .field("make_err", &type_name::<F>())
+            .field("policy", &type_name::<P>())
+            .field("inner", &self.inner)
+            .finish()
     }
 }
 
@@ -173,6 +385,31 @@ mod tests {
         assert!(matches!(error.downcast_ref(), Some(LengthLimitError)));
     }
 
+    #[derive(Debug)]
+    struct CustomError(usize);
+
+    impl fmt::Display for CustomError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "custom limit of {}", self.0)
+        }
+    }
+
+    impl Error for CustomError {}
+
+    #[tokio::test]
+    async fn read_for_body_over_limit_returns_custom_error() {
+        const DATA: &[u8] = b"testing a string that is too long";
+        let inner = Full::<_, Infallible>::new(Bytes::from(DATA));
+        let body = &mut Limited::with_error(inner, 8, CustomError);
+
+        let mut hint = SizeHint::new();
+        hint.set_upper(8);
+        assert_eq!(body.size_hint().upper(), hint.upper());
+
+        let error = body.frame().await.unwrap().unwrap_err();
+        assert!(matches!(error.downcast_ref(), Some(CustomError(8))));
+    }
+
     #[tokio::test]
     async fn read_for_chunked_body_over_limit_on_first_chunk_returns_error() {
         const DATA: [&[u8]; 2] = [b"testing a string", b" that is too long"];
@@ -210,6 +447,72 @@ mod tests {
         assert!(body.frame().await.is_none());
     }
 
+    #[tokio::test]
+    async fn remaining_tracks_consumed_bytes() {
+        const DATA: [&[u8]; 2] = [b"test", b"ing!"];
+        let inner = body_from_iter(DATA);
+        let body = &mut Limited::new(inner, 10);
+        assert_eq!(body.limit(), 10);
+        assert_eq!(body.remaining(), 10);
+
+        body.frame().await.unwrap().unwrap();
+        assert_eq!(body.remaining(), 6);
+
+        body.frame().await.unwrap().unwrap();
+        assert_eq!(body.remaining(), 2);
+        assert_eq!(body.limit(), 10);
+    }
+
+    #[tokio::test]
+    async fn adjust_budget_mid_stream() {
+        const DATA: [&[u8]; 3] = [b"test", b"ing!", b"more"];
+        let inner = body_from_iter(DATA);
+        let body = &mut Limited::new(inner, 4);
+
+        body.frame().await.unwrap().unwrap();
+        assert_eq!(body.remaining(), 0);
+
+        body.add_allowance(4);
+        assert_eq!(body.limit(), 8);
+        body.frame().await.unwrap().unwrap();
+
+        body.set_remaining(2);
+        assert_eq!(body.limit(), 10);
+        let err = body.frame().await.unwrap().unwrap_err();
+        assert!(err.downcast_ref::<LengthLimitError>().is_some());
+    }
+
+    #[tokio::test]
+    async fn split_yields_prefix_of_straddling_frame() {
+        const DATA: [&[u8]; 2] = [b"test", b"ing!"];
+        let inner = body_from_iter(DATA);
+        let body = &mut Limited::with_split(inner, 6);
+
+        let data = body.frame().await.unwrap().unwrap().into_data().unwrap();
+        assert_eq!(data, "test");
+        let data = body.frame().await.unwrap().unwrap().into_data().unwrap();
+        assert_eq!(data, "in");
+        assert_eq!(body.remaining(), 0);
+
+        let err = body.frame().await.unwrap().unwrap_err();
+        assert!(err.downcast_ref::<LengthLimitError>().is_some());
+        assert!(body.frame().await.is_none());
+        assert!(body.is_end_stream());
+    }
+
+    #[tokio::test]
+    async fn split_errors_once_when_no_budget_left() {
+        const DATA: [&[u8]; 3] = [b"test", b"ing!", b"more"];
+        let inner = body_from_iter(DATA);
+        let body = &mut Limited::with_error(inner, 4, CustomError).split();
+
+        let data = body.frame().await.unwrap().unwrap().into_data().unwrap();
+        assert_eq!(data, "test");
+        let err = body.frame().await.unwrap().unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(CustomError(4))));
+        assert!(body.frame().await.is_none());
+    }
+
     struct SomeTrailers;
 
     impl Body for SomeTrailers {
@@ -0,0 +1,324 @@
+//! Adapters between bodies and Tokio's IO traits.
+
+use std::{
+    error::Error,
+    io,
+    mem::MaybeUninit,
+    pin::Pin,
+    slice,
+    task::{Context, Poll},
+};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use futures_core::ready;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use tokio::io::{AsyncRead, ReadBuf};
+
+pin_project! {
+    /// A body created from an [`AsyncRead`].
+    ///
+    /// Each successful read is yielded as a DATA frame, until the reader reaches EOF.
+    #[derive(Debug)]
+    pub struct ReaderBody<R> {
+        #[pin]
+        reader: R,
+        buf: BytesMut,
+        chunk_size: usize,
+        len: Option<u64>,
+        eof: bool,
+    }
+}
+
+impl<R> ReaderBody<R> {
+    /// Create a new `ReaderBody` that reads up to `chunk_size` bytes per frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn new(reader: R, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be greater than zero");
+        Self {
+            reader,
+            buf: BytesMut::new(),
+            chunk_size,
+            len: None,
+            eof: false,
+        }
+    }
+
+    /// Set the number of bytes the reader is expected to yield.
+    ///
+    /// This is only used for the body's `size_hint`.
+    pub fn with_len(mut self, len: u64) -> Self {
+        self.len = Some(len);
+        self
+    }
+
+    /// Get a reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Get a mutable reference to the inner reader
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Get a pinned mutable reference to the inner reader
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut R> {
+        self.project().reader
+    }
+
+    /// Consume `self`, returning the inner reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R> Body for ReaderBody<R>
+where
+    R: AsyncRead,
+{
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+
+        if *this.eof {
+            return Poll::Ready(None);
+        }
+
+        // Read straight into the spare capacity, like `tokio_util::io::poll_read_buf`, so the
+        // buffer is neither zeroed nor reallocated on every poll. Once the previously yielded
+        // frames are dropped, `reserve` can reclaim their allocation.
+        this.buf.reserve(*this.chunk_size);
+        let n = {
+            let dst = this.buf.chunk_mut();
+            // SAFETY:
+            // `dst` points to `dst.len()` writable bytes, and `MaybeUninit` ensures the
+            // uninitialized ones are never read.
+            let dst = unsafe {
+                slice::from_raw_parts_mut(dst.as_mut_ptr().cast::<MaybeUninit<u8>>(), dst.len())
+            };
+            let len = dst.len().min(*this.chunk_size);
+            let mut read_buf = ReadBuf::uninit(&mut dst[..len]);
+            let ptr = read_buf.filled().as_ptr();
+            match this.reader.poll_read(cx, &mut read_buf) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(err)) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(Ok(())) => {}
+            }
+            // Ensure the reader did not swap the buffer out from under us.
+            assert_eq!(ptr, read_buf.filled().as_ptr());
+            read_buf.filled().len()
+        };
+
+        if n == 0 {
+            *this.eof = true;
+            return Poll::Ready(None);
+        }
+
+        // SAFETY:
+        // The reader initialized the first `n` bytes of the spare capacity.
+        unsafe { this.buf.advance_mut(n) };
+        if let Some(len) = this.len {
+            *len = len.saturating_sub(n as u64);
+        }
+        Poll::Ready(Some(Ok(Frame::data(this.buf.split().freeze()))))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.eof
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        if self.eof {
+            return SizeHint::with_exact(0);
+        }
+        match self.len {
+            Some(len) => SizeHint::with_exact(len),
+            None => SizeHint::new(),
+        }
+    }
+}
+
+pin_project! {
+    /// An [`AsyncRead`] created from a [`Body`].
+    ///
+    /// The data of each frame is buffered and copied into the caller's buffer, across as many
+    /// reads as needed. Trailers are discarded.
+    ///
+    /// See [`BodyExt::into_async_read`](crate::BodyExt::into_async_read) for more details.
+    #[derive(Debug)]
+    pub struct BodyReader<B: Body> {
+        #[pin]
+        body: B,
+        chunk: Option<B::Data>,
+    }
+}
+
+impl<B: Body> BodyReader<B> {
+    /// Create a new `BodyReader`.
+    pub fn new(body: B) -> Self {
+        Self { body, chunk: None }
+    }
+
+    /// Get a reference to the inner body
+    pub fn get_ref(&self) -> &B {
+        &self.body
+    }
+
+    /// Get a mutable reference to the inner body
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.body
+    }
+
+    /// Get a pinned mutable reference to the inner body
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut B> {
+        self.project().body
+    }
+}
+
+impl<B> AsyncRead for BodyReader<B>
+where
+    B: Body,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let mut this = self.project();
+
+        if buf.remaining() == 0 {
+            return Poll::Ready(Ok(()));
+        }
+
+        loop {
+            if let Some(chunk) = this.chunk.as_mut() {
+                if chunk.has_remaining() {
+                    let n = chunk.chunk().len().min(buf.remaining());
+                    buf.put_slice(&chunk.chunk()[..n]);
+                    chunk.advance(n);
+                    return Poll::Ready(Ok(()));
+                }
+                *this.chunk = None;
+            }
+
+            match ready!(this.body.as_mut().poll_frame(cx)) {
+                Some(Ok(frame)) => {
+                    if let Ok(data) = frame.into_data() {
+                        *this.chunk = Some(data);
+                    }
+                }
+                Some(Err(err)) => {
+                    // Keep the kind of errors that already are IO errors.
+                    let err = match err.into().downcast::<io::Error>() {
+                        Ok(err) => *err,
+                        Err(err) => io::Error::new(io::ErrorKind::Other, err),
+                    };
+                    return Poll::Ready(Err(err));
+                }
+                None => return Poll::Ready(Ok(())),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyExt;
+
+    #[tokio::test]
+    async fn reads_in_chunks() {
+        let data: &[u8] = b"hello world";
+        let mut body = ReaderBody::new(data, 4).with_len(11);
+        assert_eq!(body.size_hint().exact(), Some(11));
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "hell"
+        );
+        assert_eq!(body.size_hint().exact(), Some(7));
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "o wo"
+        );
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            "rld"
+        );
+        assert!(body.frame().await.is_none());
+        assert!(body.is_end_stream());
+    }
+
+    #[tokio::test]
+    async fn pending_reads_leave_buffer_untouched() {
+        let (mut tx, rx) = tokio::io::duplex(16);
+        let mut body = Box::pin(ReaderBody::new(rx, 8));
+
+        let waker = futures_util::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(body.as_mut().poll_frame(&mut cx).is_pending());
+        assert!(body.buf.is_empty());
+
+        tokio::io::AsyncWriteExt::write_all(&mut tx, b"hello")
+            .await
+            .unwrap();
+        drop(tx);
+        let collected = body.collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), "hello");
+    }
+
+    #[tokio::test]
+    async fn collects_reader() {
+        let data: &[u8] = b"hello world";
+        let collected = ReaderBody::new(data, 3).collect().await.unwrap();
+        assert_eq!(collected.to_bytes(), "hello world");
+    }
+
+    #[tokio::test]
+    async fn reads_across_frames() {
+        use crate::StreamBody;
+        use tokio::io::AsyncReadExt;
+
+        let frames = vec![
+            Ok::<_, io::Error>(Frame::data(Bytes::from("hel"))),
+            Ok(Frame::data(Bytes::from("lo wor"))),
+            Ok(Frame::trailers(http::HeaderMap::new())),
+            Ok(Frame::data(Bytes::from("ld"))),
+        ];
+        let mut reader = StreamBody::new(futures_util::stream::iter(frames)).into_async_read();
+
+        let mut buf = [0; 4];
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"hell");
+
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).await.unwrap();
+        assert_eq!(rest, b"o world");
+    }
+
+    #[tokio::test]
+    async fn body_error_is_io_error() {
+        use crate::StreamBody;
+        use tokio::io::AsyncReadExt;
+
+        let frames = vec![Err::<Frame<Bytes>, _>(io::Error::new(
+            io::ErrorKind::ConnectionReset,
+            "reset",
+        ))];
+        let mut reader = StreamBody::new(futures_util::stream::iter(frames)).into_async_read();
+
+        let err = reader.read_to_end(&mut Vec::new()).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionReset);
+        assert_eq!(err.to_string(), "reset");
+    }
+}
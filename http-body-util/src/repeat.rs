@@ -0,0 +1,153 @@
+use bytes::Buf;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::{
+    convert::Infallible,
+    fmt,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project! {
+    /// A body that yields the same chunk of data repeatedly.
+    pub struct Repeat<D, E = Infallible> {
+        data: D,
+        remaining: Option<usize>,
+        _marker: PhantomData<fn() -> E>,
+    }
+}
+
+impl<D, E> Repeat<D, E>
+where
+    D: Buf + Clone,
+{
+    /// Create a new `Repeat` that yields `data` as `count` DATA frames.
+    pub fn new(data: D, count: usize) -> Self {
+        let count = if data.has_remaining() { count } else { 0 };
+        Self {
+            data,
+            remaining: Some(count),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Create a new `Repeat` that yields `data` forever.
+    ///
+    /// If `data` is empty, the body ends immediately.
+    pub fn infinite(data: D) -> Self {
+        let remaining = if data.has_remaining() { None } else { Some(0) };
+        Self {
+            data,
+            remaining,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of frames left to yield, or `None` if the body never ends.
+    pub fn remaining(&self) -> Option<usize> {
+        self.remaining
+    }
+}
+
+impl<D, E> Body for Repeat<D, E>
+where
+    D: Buf + Clone,
+{
+    type Data = D;
+    type Error = E;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<D>, Self::Error>>> {
+        let this = self.project();
+        match this.remaining {
+            Some(0) => return Poll::Ready(None),
+            Some(remaining) => *remaining -= 1,
+            None => {}
+        }
+        Poll::Ready(Some(Ok(Frame::data(this.data.clone()))))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        let len = self.data.remaining() as u64;
+        match self.remaining {
+            Some(count) => match len.checked_mul(count as u64) {
+                Some(total) => SizeHint::with_exact(total),
+                None => {
+                    let mut hint = SizeHint::new();
+                    hint.set_lower(u64::MAX);
+                    hint
+                }
+            },
+            None => SizeHint::new(),
+        }
+    }
+}
+
+impl<D, E> Clone for Repeat<D, E>
+where
+    D: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            remaining: self.remaining,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<D, E> fmt::Debug for Repeat<D, E>
+where
+    D: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Repeat")
+            .field("data", &self.data)
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BodyExt;
+    use bytes::Bytes;
+
+    #[tokio::test]
+    async fn repeats_count_times() {
+        let mut body = Repeat::<_, Infallible>::new(Bytes::from("ab"), 3);
+        assert_eq!(body.size_hint().exact(), Some(6));
+
+        for _ in 0..3 {
+            assert!(!body.is_end_stream());
+            assert_eq!(
+                body.frame().await.unwrap().unwrap().into_data().unwrap(),
+                "ab"
+            );
+        }
+        assert!(body.is_end_stream());
+        assert!(body.frame().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn infinite_never_ends() {
+        let mut body = Repeat::<_, Infallible>::infinite(Bytes::from("ab"));
+        assert_eq!(body.size_hint().upper(), None);
+
+        for _ in 0..100 {
+            assert_eq!(
+                body.frame().await.unwrap().unwrap().into_data().unwrap(),
+                "ab"
+            );
+        }
+        assert!(!body.is_end_stream());
+    }
+}
@@ -1,4 +1,4 @@
-use bytes::Buf;
+use bytes::{Buf, Bytes};
 use futures_core::{ready, stream::Stream};
 use http_body::{Body, Frame};
 use pin_project_lite::pin_project;
@@ -9,6 +9,28 @@ use std::{
 
 pin_project! {
     /// A body created from a [`Stream`].
+    ///
+    /// The body's error type is the error type of the stream's items. To erase it, for example
+    /// to store bodies built from different streams behind one type, use
+    /// [`BodyExt::map_err_boxed`] on the body rather than mapping the stream.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use http_body::Frame;
+    /// use http_body_util::{BodyExt, StreamBody};
+    /// use std::{error::Error, io};
+    ///
+    /// let stream = futures_util::stream::iter([
+    ///     Ok(Frame::data(Bytes::from("hello"))),
+    ///     Err(io::Error::new(io::ErrorKind::Other, "oops")),
+    /// ]);
+    /// let body = StreamBody::new(stream).map_err_boxed();
+    /// # let _: &dyn http_body::Body<Data = Bytes, Error = Box<dyn Error + Send + Sync>> = &body;
+    /// ```
+    ///
+    /// [`BodyExt::map_err_boxed`]: crate::BodyExt::map_err_boxed
     #[derive(Clone, Copy, Debug)]
     pub struct StreamBody<S> {
         #[pin]
@@ -21,6 +43,11 @@ impl<S> StreamBody<S> {
     pub fn new(stream: S) -> Self {
         Self { stream }
     }
+
+    /// Consume `self`, returning the inner stream
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
 }
 
 impl<S, D, E> Body for StreamBody<S>
@@ -55,6 +82,56 @@ impl<S: Stream> Stream for StreamBody<S> {
     }
 }
 
+pin_project! {
+    /// A body created from a [`Stream`] of data chunks.
+    ///
+    /// Unlike [`StreamBody`], the stream yields `Result<D, E>` items, each of which is wrapped
+    /// in a DATA frame.
+    #[derive(Clone, Copy, Debug)]
+    pub struct DataStreamBody<S> {
+        #[pin]
+        stream: S,
+    }
+}
+
+impl<S> DataStreamBody<S> {
+    /// Create a new `DataStreamBody`.
+    pub fn new(stream: S) -> Self {
+        Self { stream }
+    }
+}
+
+impl<S, D, E> Body for DataStreamBody<S>
+where
+    S: Stream<Item = Result<D, E>>,
+    D: Buf,
+{
+    type Data = D;
+    type Error = E;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        match ready!(self.project().stream.poll_next(cx)) {
+            Some(result) => Poll::Ready(Some(result.map(Frame::data))),
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+impl<S: Stream> Stream for DataStreamBody<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().stream.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
 pin_project! {
     /// A stream created from a [`Body`].
     #[derive(Clone, Copy, Debug)]
@@ -69,6 +146,11 @@ impl<B> BodyStream<B> {
     pub fn new(body: B) -> Self {
         Self { body }
     }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_body(self) -> B {
+        self.body
+    }
 }
 
 impl<B> Body for BodyStream<B>
@@ -84,6 +166,14 @@ where
     ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
         self.project().body.poll_frame(cx)
     }
+
+    fn is_end_stream(&self) -> bool {
+        self.body.is_end_stream()
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        self.body.size_hint()
+    }
 }
 
 impl<B> Stream for BodyStream<B>
@@ -99,6 +189,17 @@ where
             Poll::Pending => Poll::Pending,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The number of frames is unknown, but a body with data left yields at least one.
+        if self.body.is_end_stream() {
+            (0, Some(0))
+        } else if self.body.size_hint().lower() > 0 {
+            (1, None)
+        } else {
+            (0, None)
+        }
+    }
 }
 
 pin_project! {
@@ -115,6 +216,11 @@ impl<B> BodyDataStream<B> {
     pub fn new(body: B) -> Self {
         Self { body }
     }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.body
+    }
 }
 
 impl<B> Stream for BodyDataStream<B>
@@ -137,12 +243,56 @@ where
     }
 }
 
+pin_project! {
+    /// A stream of [`Bytes`] created from a [`Body`].
+    ///
+    /// Each data buffer is converted with [`Buf::copy_to_bytes`], which does not copy when the
+    /// buffer is already [`Bytes`]. Trailers are discarded.
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyBytesStream<B> {
+        #[pin]
+        body: B,
+    }
+}
+
+impl<B> BodyBytesStream<B> {
+    /// Create a new `BodyBytesStream`
+    pub fn new(body: B) -> Self {
+        Self { body }
+    }
+
+    /// Consume `self`, returning the inner body
+    pub fn into_inner(self) -> B {
+        self.body
+    }
+}
+
+impl<B> Stream for BodyBytesStream<B>
+where
+    B: Body,
+{
+    type Item = Result<Bytes, B::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            return match ready!(self.as_mut().project().body.poll_frame(cx)) {
+                Some(Ok(frame)) => match frame.into_data() {
+                    Ok(mut data) => Poll::Ready(Some(Ok(data.copy_to_bytes(data.remaining())))),
+                    Err(_) => continue,
+                },
+                Some(Err(err)) => Poll::Ready(Some(Err(err))),
+                None => Poll::Ready(None),
+            };
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{BodyExt, BodyStream, StreamBody};
+    use crate::{BodyExt, BodyStream, DataStreamBody, Full, StreamBody};
     use bytes::Bytes;
     use futures_util::StreamExt;
-    use http_body::Frame;
+    use http_body::{Body, Frame};
     use std::convert::Infallible;
 
     #[tokio::test]
@@ -189,6 +339,42 @@ mod tests {
         assert!(body.frame().await.is_none());
     }
 
+    #[tokio::test]
+    async fn stream_body_into_inner() {
+        let chunks: Vec<Result<_, Infallible>> = vec![
+            Ok(Frame::data(Bytes::from(vec![1]))),
+            Ok(Frame::data(Bytes::from(vec![2]))),
+        ];
+        let mut body = StreamBody::new(futures_util::stream::iter(chunks));
+
+        assert_eq!(
+            body.frame().await.unwrap().unwrap().into_data().unwrap(),
+            [1].as_ref()
+        );
+
+        let mut stream = body.into_inner();
+        let frame = stream.next().await.unwrap().unwrap();
+        assert_eq!(frame.into_data().unwrap(), [2].as_ref());
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn body_stream_size_hint_and_into_body() {
+        let body = crate::Full::<_, Infallible>::new(Bytes::from("hello"));
+        let mut stream = BodyStream::new(body);
+        assert_eq!(futures_util::Stream::size_hint(&stream), (1, None));
+        assert_eq!(Body::size_hint(&stream).exact(), Some(5));
+
+        let data = StreamExt::by_ref(&mut stream)
+            .map(|frame| frame.unwrap().into_data().unwrap())
+            .next()
+            .await
+            .unwrap();
+        assert_eq!(data, "hello");
+        assert_eq!(futures_util::Stream::size_hint(&stream), (0, Some(0)));
+        assert!(stream.into_body().is_end_stream());
+    }
+
     #[tokio::test]
     async fn stream_from_body() {
         let chunks: Vec<Result<_, Infallible>> = vec![
@@ -237,4 +423,28 @@ mod tests {
 
         assert!(stream.next().await.is_none());
     }
+
+    #[tokio::test]
+    async fn body_from_data_stream() {
+        let chunks: Vec<Result<_, Infallible>> =
+            vec![Ok(Bytes::from("hello")), Ok(Bytes::from(" world"))];
+        let body = DataStreamBody::new(futures_util::stream::iter(chunks));
+
+        assert_eq!(Body::size_hint(&body).exact(), None);
+        let collected = BodyExt::collect(body).await.unwrap();
+        assert!(collected.trailers().is_none());
+        assert_eq!(collected.to_bytes(), "hello world");
+    }
+
+    #[tokio::test]
+    async fn bytes_stream_from_body() {
+        let mut trailers = http::HeaderMap::new();
+        trailers.insert("x-done", "1".parse().unwrap());
+        let body = Full::<_, Infallible>::new(&b"hello"[..]).chain(
+            Full::<_, Infallible>::with_trailers(&b" world"[..], trailers),
+        );
+
+        let chunks: Vec<Bytes> = body.into_bytes_stream().map(Result::unwrap).collect().await;
+        assert_eq!(chunks, [Bytes::from("hello"), Bytes::from(" world")]);
+    }
 }
@@ -1,9 +1,11 @@
 use std::collections::VecDeque;
 use std::io::IoSlice;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
 
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct BufList<T> {
     bufs: VecDeque<T>,
 }
@@ -105,6 +107,76 @@ impl<T> Default for BufList<T> {
     }
 }
 
+/// Create a single-use slot for handing a value from a body to a future.
+///
+/// The receiver resolves once [`Sender::send`] is called or the sender is dropped.
+pub(crate) fn oneshot<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Mutex::new(Shared {
+        value: None,
+        complete: false,
+        waker: None,
+    }));
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+#[derive(Debug)]
+struct Shared<T> {
+    value: Option<T>,
+    complete: bool,
+    waker: Option<Waker>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Sender<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> Sender<T> {
+    pub(crate) fn send(self, value: Option<T>) {
+        self.shared.lock().unwrap().value = value;
+    }
+
+    /// Change the value that the receiver will get, without completing it.
+    pub(crate) fn update(&self, f: impl FnOnce(&mut Option<T>)) {
+        f(&mut self.shared.lock().unwrap().value);
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut shared = self.shared.lock().unwrap();
+            shared.complete = true;
+            shared.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Receiver<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> Receiver<T> {
+    pub(crate) fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut shared = self.shared.lock().unwrap();
+        if shared.complete {
+            Poll::Ready(shared.value.take())
+        } else {
+            shared.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::ptr;
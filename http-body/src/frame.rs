@@ -16,6 +16,16 @@ enum Kind<T> {
     //Unknown(Box<dyn Frameish>),
 }
 
+/// The kind of a [`Frame`], as returned by [`Frame::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FrameKind {
+    /// A DATA frame.
+    Data,
+    /// A trailers frame.
+    Trailers,
+}
+
 impl<T> Frame<T> {
     /// Create a DATA frame with the provided `Buf`.
     pub fn data(buf: T) -> Self {
@@ -46,6 +56,14 @@ impl<T> Frame<T> {
         }
     }
 
+    /// Returns the kind of this frame.
+    pub fn kind(&self) -> FrameKind {
+        match self.kind {
+            Kind::Data(..) => FrameKind::Data,
+            Kind::Trailers(..) => FrameKind::Trailers,
+        }
+    }
+
     /// Returns whether this is a DATA frame.
     pub fn is_data(&self) -> bool {
         matches!(self.kind, Kind::Data(..))
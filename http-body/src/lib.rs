@@ -16,7 +16,7 @@
 mod frame;
 mod size_hint;
 
-pub use self::frame::Frame;
+pub use self::frame::{Frame, FrameKind};
 pub use self::size_hint::SizeHint;
 
 use bytes::{Buf, Bytes};
@@ -186,6 +186,35 @@ impl<B: Body> Body for http::Response<B> {
     }
 }
 
+impl<B: Body> Body for Option<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        match self.as_pin_mut() {
+            Some(body) => body.poll_frame(cx),
+            None => Poll::Ready(None),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        match self {
+            Some(body) => body.is_end_stream(),
+            None => true,
+        }
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        match self {
+            Some(body) => body.size_hint(),
+            None => SizeHint::with_exact(0),
+        }
+    }
+}
+
 impl Body for String {
     type Data = Bytes;
     type Error = Infallible;
@@ -1,3 +1,5 @@
+use std::ops::Add;
+
 /// A `Body` size hint
 ///
 /// The default implementation returns:
@@ -81,4 +83,46 @@ impl SizeHint {
         self.lower = value;
         self.upper = Some(value);
     }
+
+    /// Returns a new `SizeHint` with both bounds reduced by `n`, saturating at zero.
+    ///
+    /// This is useful for accounting for data that has already been consumed.
+    #[inline]
+    pub fn saturating_sub(&self, n: u64) -> SizeHint {
+        SizeHint {
+            lower: self.lower.saturating_sub(n),
+            upper: self.upper.map(|upper| upper.saturating_sub(n)),
+        }
+    }
+
+    /// Returns a new `SizeHint` with both bounds clamped to at most `n`.
+    ///
+    /// This is useful for bodies that stop after a limited number of bytes.
+    #[inline]
+    pub fn clamp_upper(&self, n: u64) -> SizeHint {
+        SizeHint {
+            lower: self.lower.min(n),
+            upper: Some(self.upper.map_or(n, |upper| upper.min(n))),
+        }
+    }
+}
+
+/// Combines the size hints of two bodies that are yielded one after the other.
+///
+/// The lower bounds are summed, saturating at `u64::MAX`. The upper bound is only known if
+/// both upper bounds are known and their sum does not overflow.
+impl Add for SizeHint {
+    type Output = SizeHint;
+
+    #[inline]
+    fn add(self, other: SizeHint) -> SizeHint {
+        let upper = match (self.upper, other.upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        SizeHint {
+            lower: self.lower.saturating_add(other.lower),
+            upper,
+        }
+    }
 }
@@ -0,0 +1,13 @@
+use http::HeaderMap;
+use http_body::{Frame, FrameKind};
+
+#[test]
+fn kind() {
+    let data = Frame::data("hello");
+    assert_eq!(data.kind(), FrameKind::Data);
+    assert!(data.is_data());
+
+    let trailers = Frame::<&str>::trailers(HeaderMap::new());
+    assert_eq!(trailers.kind(), FrameKind::Trailers);
+    assert!(trailers.is_trailers());
+}
@@ -68,3 +68,16 @@ fn is_end_stream_default_false() {
         mock.size_hint.clone()
     );
 }
+
+#[test]
+fn is_end_stream_option() {
+    let none: Option<Mock> = None;
+    assert!(none.is_end_stream());
+    assert_eq!(none.size_hint().exact(), Some(0));
+
+    let some = Some(Mock {
+        size_hint: SizeHint::with_exact(123),
+    });
+    assert!(!some.is_end_stream());
+    assert_eq!(some.size_hint().exact(), Some(123));
+}
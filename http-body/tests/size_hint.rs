@@ -0,0 +1,58 @@
+use http_body::SizeHint;
+
+fn hint(lower: u64, upper: Option<u64>) -> SizeHint {
+    let mut hint = SizeHint::new();
+    if let Some(upper) = upper {
+        hint.set_upper(upper);
+    }
+    hint.set_lower(lower);
+    hint
+}
+
+#[test]
+fn add() {
+    let combos = [
+        ((0, None), (0, None), (0, None)),
+        ((1, Some(2)), (3, None), (4, None)),
+        ((1, Some(2)), (3, Some(4)), (4, Some(6))),
+        ((u64::MAX, Some(u64::MAX)), (1, Some(1)), (u64::MAX, None)),
+    ];
+
+    for ((a_lower, a_upper), (b_lower, b_upper), (lower, upper)) in combos {
+        let sum = hint(a_lower, a_upper) + hint(b_lower, b_upper);
+        assert_eq!(sum.lower(), lower);
+        assert_eq!(sum.upper(), upper);
+    }
+}
+
+#[test]
+fn saturating_sub() {
+    let combos = [
+        ((0, None), 5, (0, None)),
+        ((3, Some(10)), 5, (0, Some(5))),
+        ((10, Some(10)), 4, (6, Some(6))),
+        ((10, Some(10)), 20, (0, Some(0))),
+    ];
+
+    for ((lower, upper), n, (expected_lower, expected_upper)) in combos {
+        let hint = hint(lower, upper).saturating_sub(n);
+        assert_eq!(hint.lower(), expected_lower);
+        assert_eq!(hint.upper(), expected_upper);
+    }
+}
+
+#[test]
+fn clamp_upper() {
+    let combos = [
+        ((0, None), 5, (0, Some(5))),
+        ((3, Some(10)), 5, (3, Some(5))),
+        ((3, Some(4)), 5, (3, Some(4))),
+        ((10, Some(10)), 4, (4, Some(4))),
+    ];
+
+    for ((lower, upper), n, (expected_lower, expected_upper)) in combos {
+        let hint = hint(lower, upper).clamp_upper(n);
+        assert_eq!(hint.lower(), expected_lower);
+        assert_eq!(hint.upper(), expected_upper);
+    }
+}